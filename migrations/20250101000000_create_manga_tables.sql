@@ -0,0 +1,45 @@
+-- Tables used by the helpers in `tosho::db`.
+--
+-- Manga and chapters are keyed by their source, since IDs are only unique
+-- within a single source. Authors and tags keep their original order through
+-- the `position` column.
+
+CREATE TABLE IF NOT EXISTS manga (
+    source_id TEXT NOT NULL,
+    id TEXT NOT NULL,
+    title TEXT NOT NULL,
+    cover_url TEXT,
+    url TEXT,
+    description TEXT,
+    PRIMARY KEY (source_id, id)
+);
+
+CREATE TABLE IF NOT EXISTS manga_authors (
+    source_id TEXT NOT NULL,
+    manga_id TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    PRIMARY KEY (source_id, manga_id, position),
+    FOREIGN KEY (source_id, manga_id) REFERENCES manga (source_id, id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS manga_tags (
+    source_id TEXT NOT NULL,
+    manga_id TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    PRIMARY KEY (source_id, manga_id, position),
+    FOREIGN KEY (source_id, manga_id) REFERENCES manga (source_id, id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS chapters (
+    source_id TEXT NOT NULL,
+    id TEXT NOT NULL,
+    manga_id TEXT NOT NULL,
+    number REAL NOT NULL,
+    volume REAL,
+    title TEXT NOT NULL,
+    PRIMARY KEY (source_id, id)
+);
+
+CREATE INDEX IF NOT EXISTS chapters_by_manga ON chapters (source_id, manga_id);
@@ -0,0 +1,233 @@
+//! SQLite storage for manga and chapters.
+//!
+//! This module provides ready-made queries for the schema in `migrations/`,
+//! so [`Manga`] and [`Chapter`] can be stored without hand-writing SQL.
+//! Fields marked `sqlx(skip)` that have no column of their own, such as a
+//! manga's authors and tags, are kept in separate tables and loaded back by
+//! the helpers here. Only available with the `db-sqlite` feature.
+//!
+//! Content ratings, available languages and chapter page URLs are not stored.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use tosho::db;
+//! use sqlx::SqlitePool;
+//!
+//! # async fn example(manga: tosho::Manga) -> tosho::Result<()> {
+//! let pool = SqlitePool::connect("sqlite://library.db").await?;
+//! db::MIGRATOR.run(&pool).await.map_err(sqlx::Error::from)?;
+//!
+//! db::upsert_manga(&pool, &manga).await?;
+//! let stored = db::fetch_manga(&pool, &manga.source_id, &manga.id).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Result;
+use crate::types::{Chapter, Manga};
+use sqlx::migrate::Migrator;
+use sqlx::{SqliteConnection, SqlitePool};
+
+/// Migrations creating the tables used by this module.
+pub static MIGRATOR: Migrator = sqlx::migrate!();
+
+/// Inserts a manga, or replaces the stored copy with the same source and ID.
+///
+/// The main row, authors and tags are written in a single transaction, so a
+/// failed write never leaves a manga with partial metadata. Authors and tags
+/// already stored for the manga are replaced, not merged.
+///
+/// # Parameters
+///
+/// * `pool` - The database to write to
+/// * `manga` - The manga to store
+///
+/// # Errors
+///
+/// Returns [`Error::Database`](crate::Error::Database) if any statement fails.
+pub async fn upsert_manga(pool: &SqlitePool, manga: &Manga) -> Result<()> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        "INSERT INTO manga (source_id, id, title, cover_url, url, description)
+         VALUES (?, ?, ?, ?, ?, ?)
+         ON CONFLICT (source_id, id) DO UPDATE SET
+             title = excluded.title,
+             cover_url = excluded.cover_url,
+             url = excluded.url,
+             description = excluded.description",
+    )
+    .bind(&manga.source_id)
+    .bind(&manga.id)
+    .bind(&manga.title)
+    .bind(&manga.cover_url)
+    .bind(&manga.url)
+    .bind(&manga.description)
+    .execute(&mut *tx)
+    .await?;
+
+    replace_names(&mut tx, "manga_authors", manga, &manga.authors).await?;
+    replace_names(&mut tx, "manga_tags", manga, &manga.tags).await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Loads a stored manga together with its authors and tags.
+///
+/// # Parameters
+///
+/// * `pool` - The database to read from
+/// * `source_id` - The source the manga belongs to
+/// * `id` - The manga's ID within that source
+///
+/// # Returns
+///
+/// The stored manga, or `None` if it was never stored.
+///
+/// # Errors
+///
+/// Returns [`Error::Database`](crate::Error::Database) if any query fails.
+pub async fn fetch_manga(pool: &SqlitePool, source_id: &str, id: &str) -> Result<Option<Manga>> {
+    // Read in one transaction so the row and its lists come from the same write
+    let mut tx = pool.begin().await?;
+
+    let manga: Option<Manga> = sqlx::query_as(
+        "SELECT source_id, id, title, cover_url, url, description
+         FROM manga WHERE source_id = ? AND id = ?",
+    )
+    .bind(source_id)
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let Some(mut manga) = manga else {
+        return Ok(None);
+    };
+
+    manga.authors = fetch_names(&mut tx, "manga_authors", source_id, id).await?;
+    manga.tags = fetch_names(&mut tx, "manga_tags", source_id, id).await?;
+
+    tx.commit().await?;
+    Ok(Some(manga))
+}
+
+/// Inserts chapters, replacing stored copies with the same source and ID.
+///
+/// All chapters are written in a single transaction.
+///
+/// # Parameters
+///
+/// * `pool` - The database to write to
+/// * `chapters` - The chapters to store
+///
+/// # Errors
+///
+/// Returns [`Error::Database`](crate::Error::Database) if any statement fails.
+pub async fn upsert_chapters(pool: &SqlitePool, chapters: &[Chapter]) -> Result<()> {
+    let mut tx = pool.begin().await?;
+
+    for chapter in chapters {
+        sqlx::query(
+            "INSERT INTO chapters (source_id, id, manga_id, number, volume, title)
+             VALUES (?, ?, ?, ?, ?, ?)
+             ON CONFLICT (source_id, id) DO UPDATE SET
+                 manga_id = excluded.manga_id,
+                 number = excluded.number,
+                 volume = excluded.volume,
+                 title = excluded.title",
+        )
+        .bind(&chapter.source_id)
+        .bind(&chapter.id)
+        .bind(&chapter.manga_id)
+        .bind(chapter.number)
+        .bind(chapter.volume)
+        .bind(&chapter.title)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Loads the stored chapters of a manga in reading order.
+///
+/// # Parameters
+///
+/// * `pool` - The database to read from
+/// * `source_id` - The source the manga belongs to
+/// * `manga_id` - The manga's ID within that source
+///
+/// # Returns
+///
+/// The stored chapters sorted like [`Chapter::cmp_by_number`], or an empty
+/// vector if none were stored.
+///
+/// # Errors
+///
+/// Returns [`Error::Database`](crate::Error::Database) if the query fails.
+pub async fn fetch_chapters(
+    pool: &SqlitePool,
+    source_id: &str,
+    manga_id: &str,
+) -> Result<Vec<Chapter>> {
+    let mut chapters: Vec<Chapter> = sqlx::query_as(
+        "SELECT source_id, id, manga_id, number, volume, title
+         FROM chapters WHERE source_id = ? AND manga_id = ?",
+    )
+    .bind(source_id)
+    .bind(manga_id)
+    .fetch_all(pool)
+    .await?;
+
+    chapters.sort_by(Chapter::cmp_by_number);
+    Ok(chapters)
+}
+
+// Replaces the ordered names stored for a manga in one of the list tables
+async fn replace_names(
+    conn: &mut SqliteConnection,
+    table: &str,
+    manga: &Manga,
+    names: &[String],
+) -> Result<()> {
+    sqlx::query(&format!(
+        "DELETE FROM {table} WHERE source_id = ? AND manga_id = ?"
+    ))
+    .bind(&manga.source_id)
+    .bind(&manga.id)
+    .execute(&mut *conn)
+    .await?;
+
+    let insert =
+        format!("INSERT INTO {table} (source_id, manga_id, position, name) VALUES (?, ?, ?, ?)");
+    for (position, name) in names.iter().enumerate() {
+        sqlx::query(&insert)
+            .bind(&manga.source_id)
+            .bind(&manga.id)
+            .bind(position as i64)
+            .bind(name)
+            .execute(&mut *conn)
+            .await?;
+    }
+
+    Ok(())
+}
+
+// Loads the ordered names stored for a manga in one of the list tables
+async fn fetch_names(
+    conn: &mut SqliteConnection,
+    table: &str,
+    source_id: &str,
+    manga_id: &str,
+) -> Result<Vec<String>> {
+    Ok(sqlx::query_scalar(&format!(
+        "SELECT name FROM {table} WHERE source_id = ? AND manga_id = ? ORDER BY position"
+    ))
+    .bind(source_id)
+    .bind(manga_id)
+    .fetch_all(&mut *conn)
+    .await?)
+}
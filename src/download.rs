@@ -4,9 +4,9 @@
 //! Downloads are handled directly by the sources using their `download_chapter` method.
 
 use crate::error::{Error, Result};
-use std::path::Path;
+use crate::types::{Chapter, Manga};
+use std::path::{Path, PathBuf};
 use tokio::fs;
-use tokio::io::AsyncWriteExt;
 
 /// Downloads a single file from a URL to a local path.
 ///
@@ -66,17 +66,79 @@ pub async fn download_file(url: &str, output_path: &Path) -> Result<u64> {
             .map_err(|e| Error::source("download", format!("Failed to create directory: {}", e)))?;
     }
 
-    let mut file = fs::File::create(output_path)
-        .await
-        .map_err(|e| Error::source("download", format!("Failed to create file: {}", e)))?;
-
-    file.write_all(&bytes)
+    write_file_atomic(output_path, &bytes)
         .await
         .map_err(|e| Error::source("download", format!("Failed to write file: {}", e)))?;
 
     Ok(bytes.len() as u64)
 }
 
+/// Extension appended to files while they are being written.
+///
+/// A file with this extension is an interrupted write; it never holds a
+/// complete page and can be deleted.
+pub const PART_EXTENSION: &str = "part";
+
+/// Writes a file so that it either exists complete or not at all.
+///
+/// The data is written to a `<name>.part` file next to `path` first and
+/// renamed into place only once it was fully written and flushed. If the
+/// process is killed mid-write, only the `.part` file is left behind, so a
+/// truncated page is never mistaken for a downloaded one. On a failed write
+/// the `.part` file is removed.
+///
+/// # Parameters
+///
+/// * `path` - Where the file should end up
+/// * `data` - The complete file contents
+///
+/// # Errors
+///
+/// Returns [`Error::Io`] if the file can't be written or renamed.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tosho::download::write_file_atomic;
+/// use std::path::Path;
+///
+/// # async fn example(image: &[u8]) -> tosho::Result<()> {
+/// write_file_atomic(Path::new("./downloads/001.jpg"), image).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn write_file_atomic(path: &Path, data: &[u8]) -> Result<()> {
+    write_atomic_from(path, data).await
+}
+
+/// Streams `reader` into `path` through a `.part` file, see [`write_file_atomic`].
+async fn write_atomic_from<R>(path: &Path, mut reader: R) -> Result<()>
+where
+    R: tokio::io::AsyncRead + Unpin,
+{
+    let mut part_name = path.file_name().unwrap_or_default().to_os_string();
+    part_name.push(".");
+    part_name.push(PART_EXTENSION);
+    let part_path = path.with_file_name(part_name);
+
+    let written = async {
+        let mut file = fs::File::create(&part_path).await?;
+        tokio::io::copy(&mut reader, &mut file).await?;
+        file.sync_all().await?;
+        drop(file);
+        fs::rename(&part_path, path).await
+    }
+    .await;
+
+    if let Err(e) = written {
+        // Best effort: the write already failed, a leftover .part file is harmless
+        let _ = fs::remove_file(&part_path).await;
+        return Err(e.into());
+    }
+
+    Ok(())
+}
+
 /// Sanitizes a filename by replacing invalid characters.
 ///
 /// This function removes or replaces characters that are not allowed in filenames
@@ -120,10 +182,15 @@ pub fn sanitize_filename(name: &str) -> String {
     sanitized
 }
 
-/// Extracts file extension from a URL.
+/// Image file extensions recognized by [`extract_extension`].
+const IMAGE_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "webp", "gif", "bmp", "avif"];
+
+/// Extracts the image file extension from a URL.
 ///
-/// This function attempts to determine the file extension from a URL,
-/// ignoring query parameters and fragments.
+/// Fragments and query parameters are stripped first, then the last dot-segment
+/// of the path is used if it is a known image extension (`jpg`, `jpeg`, `png`,
+/// `webp`, `gif`, `bmp` or `avif`). Otherwise, CDNs that pick the format through
+/// a `format=` query parameter are handled by falling back to that parameter.
 ///
 /// # Parameters
 ///
@@ -131,7 +198,7 @@ pub fn sanitize_filename(name: &str) -> String {
 ///
 /// # Returns
 ///
-/// The file extension (without the dot) if found, None otherwise.
+/// The lowercase file extension (without the dot) if found, None otherwise.
 ///
 /// # Examples
 ///
@@ -140,24 +207,488 @@ pub fn sanitize_filename(name: &str) -> String {
 ///
 /// assert_eq!(extract_extension("https://example.com/image.jpg"), Some("jpg".to_string()));
 /// assert_eq!(extract_extension("https://example.com/image.png?v=123"), Some("png".to_string()));
+/// assert_eq!(extract_extension("https://cdn.example.com/image?format=webp"), Some("webp".to_string()));
 /// assert_eq!(extract_extension("https://example.com/image"), None);
 /// ```
 pub fn extract_extension(url: &str) -> Option<String> {
-    // Remove query parameters and fragments
-    let clean_url = url.split('?').next()?.split('#').next()?;
+    let is_image = |ext: &str| IMAGE_EXTENSIONS.contains(&ext);
+
+    // Separate the path from query parameters and fragments
+    let without_fragment = url.split('#').next()?;
+    let (clean_url, query) = match without_fragment.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (without_fragment, None),
+    };
+
+    // Get the last path segment
+    let path = clean_url.split('/').next_back()?;
+
+    if let Some((_, ext)) = path.rsplit_once('.') {
+        let ext = ext.to_lowercase();
+        if is_image(&ext) {
+            return Some(ext);
+        }
+    }
+
+    // Fall back to a format query parameter, e.g. `?format=webp`
+    query?
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| key.eq_ignore_ascii_case("format"))
+        .map(|(_, value)| value.to_lowercase())
+        .filter(|ext| is_image(ext))
+}
+
+/// Name of the sidecar file, inside a manga's download directory, that holds the
+/// serialized [`Manga`].
+///
+/// Download directories are named after [`Manga::slug()`]; this file keeps the
+/// readable title and the rest of the metadata (authors, tags, description)
+/// alongside the chapter directories. See [`write_manga_metadata`] and
+/// [`read_manga_metadata`].
+pub const MANGA_METADATA_FILE: &str = "metadata.json";
+
+/// Writes a manga's metadata sidecar into its download directory.
+///
+/// The manga is serialized as JSON to [`MANGA_METADATA_FILE`] inside `dir`,
+/// replacing any previous sidecar. [`Source::download_chapter`](crate::Source::download_chapter)
+/// calls this automatically for the manga directory of every chapter it downloads.
+/// The file is written with [`write_file_atomic`], so an interrupted write
+/// never leaves a truncated sidecar, and is left untouched if its contents
+/// wouldn't change.
+///
+/// # Parameters
+///
+/// * `manga` - The manga to record
+/// * `dir` - The manga's download directory (created if missing)
+///
+/// # Returns
+///
+/// The path of the written sidecar file.
+///
+/// # Errors
+///
+/// Returns an error if the directory or the file can't be written.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tosho::download::write_manga_metadata;
+/// use std::path::Path;
+///
+/// # async fn example(manga: &tosho::Manga) -> tosho::Result<()> {
+/// let manga_dir = Path::new("./downloads").join(manga.slug());
+/// write_manga_metadata(manga, &manga_dir).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn write_manga_metadata(manga: &Manga, dir: &Path) -> Result<PathBuf> {
+    let path = dir.join(MANGA_METADATA_FILE);
+    let json = serde_json::to_vec_pretty(manga)?;
+
+    // Every chapter download rewrites the sidecar; skip it when nothing changed
+    if fs::read(&path).await.is_ok_and(|existing| existing == json) {
+        return Ok(path);
+    }
+
+    fs::create_dir_all(dir)
+        .await
+        .map_err(|e| Error::source("download", format!("Failed to create directory: {}", e)))?;
+    write_file_atomic(&path, &json)
+        .await
+        .map_err(|e| Error::source("download", format!("Failed to write metadata: {}", e)))?;
+
+    Ok(path)
+}
+
+/// Reads the metadata sidecar written by [`write_manga_metadata`].
+///
+/// This recovers the full [`Manga`] for a download directory, e.g. to convert
+/// or re-index previously downloaded chapters without contacting the source.
+///
+/// # Parameters
+///
+/// * `dir` - The manga's download directory
+///
+/// # Errors
+///
+/// * [`Error::NotFound`] - If the directory has no sidecar
+/// * [`Error::Json`] - If the sidecar isn't a valid serialized manga
+/// * [`Error::Source`] - If the sidecar can't be read
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tosho::download::read_manga_metadata;
+/// use std::path::Path;
+///
+/// # async fn example() -> tosho::Result<()> {
+/// let manga = read_manga_metadata(Path::new("./downloads/one-piece-mgd-3f2a9c1e")).await?;
+/// println!("{} by {:?}", manga.title, manga.authors);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn read_manga_metadata(dir: &Path) -> Result<Manga> {
+    let path = dir.join(MANGA_METADATA_FILE);
+    let json = fs::read(&path).await.map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            Error::not_found(format!("Manga metadata: {}", path.display()))
+        } else {
+            Error::source("download", format!("Failed to read metadata: {}", e))
+        }
+    })?;
+
+    Ok(serde_json::from_slice(&json)?)
+}
+
+/// Returns the directory a chapter is downloaded to.
+///
+/// This is the layout used by [`Source::download_chapter`](crate::Source::download_chapter):
+/// `output_dir/%MANGA_SLUG%/%VOL%-%CH%`, where the manga directory is named after
+/// [`Manga::slug()`], the volume is zero-padded to three digits (0 if unknown) and
+/// whole chapter numbers are zero-padded to three digits (`012`) while fractional
+/// ones keep one decimal (`12.5`). The readable title is kept next to the chapter
+/// directories in a [`MANGA_METADATA_FILE`].
+///
+/// # Parameters
+///
+/// * `output_dir` - Base download directory
+/// * `manga` - The manga, whose slug names the top-level directory
+/// * `chapter` - The chapter, used for volume/chapter numbers
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::download::chapter_dir;
+/// use tosho::types::{Chapter, Manga};
+/// use std::path::Path;
+/// # let manga = Manga {
+/// #     id: "op".to_string(),
+/// #     url: None,
+/// #     title: "One Piece".to_string(),
+/// #     authors: vec![],
+/// #     source_id: "mgd".to_string(),
+/// #     cover_url: None,
+/// #     description: None,
+/// #     tags: vec![],
+/// #     content_rating: None,
+/// #     available_languages: vec![],
+/// # };
+/// # let chapter = Chapter {
+/// #     id: "ch".to_string(),
+/// #     number: 12.5,
+/// #     volume: Some(2.0),
+/// #     title: String::new(),
+/// #     pages: vec![],
+/// #     page_count: None,
+/// #     manga_id: "op".to_string(),
+/// #     source_id: "mgd".to_string(),
+/// # };
+///
+/// let dir = chapter_dir(Path::new("downloads"), &manga, &chapter);
+/// assert_eq!(dir, Path::new("downloads").join(manga.slug()).join("002-12.5"));
+/// ```
+pub fn chapter_dir(output_dir: &Path, manga: &Manga, chapter: &Chapter) -> PathBuf {
+    // Default to volume 0 if not present, and cast to u32 for padding.
+    let vol_num = chapter.volume.unwrap_or(0.0) as u32;
+
+    // Handle chapter numbers with decimals (e.g., 99.5) separately from integers.
+    let chapter_number_str = {
+        let fract = chapter.number.fract();
+        // Use a small tolerance for floating point comparison
+        if fract.abs() > 0.001 {
+            // It has a decimal part, e.g., 99.5 -> "99.5"
+            format!("{:.1}", chapter.number)
+        } else {
+            // It's a whole number, e.g., 100.0 -> "100". Pad it.
+            format!("{:03}", chapter.number as u32)
+        }
+    };
+
+    let chapter_name = format!("{:03}-{}", vol_num, chapter_number_str);
+
+    output_dir
+        .join(manga.slug())
+        .join(sanitize_filename(&chapter_name))
+}
+
+/// Checks whether a chapter has already been downloaded.
+///
+/// A chapter counts as downloaded when its [`chapter_dir`] contains page
+/// files named by page number, as the default [`NamingStrategy`] and the other
+/// numbered strategies save them (`001.jpg`, `7.png`). Other files, such as
+/// `.DS_Store` or interrupted writes ending in [`PART_EXTENSION`], are
+/// ignored, so chapters saved with [`NamingStrategy::PreserveOriginal`] are
+/// not detected. If the chapter's page count is known, from
+/// [`Chapter::page_count`] or its `pages`, that many page files are required;
+/// otherwise a single one is enough.
+///
+/// # Parameters
+///
+/// * `output_dir` - Base download directory the chapter was downloaded to
+/// * `manga` - The manga the chapter belongs to
+/// * `chapter` - The chapter to check
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tosho::download::chapter_is_downloaded;
+/// use std::path::Path;
+///
+/// # fn example(manga: &tosho::Manga, chapter: &tosho::Chapter) {
+/// if !chapter_is_downloaded(Path::new("./downloads"), manga, chapter) {
+///     // queue the download
+/// }
+/// # }
+/// ```
+pub fn chapter_is_downloaded(output_dir: &Path, manga: &Manga, chapter: &Chapter) -> bool {
+    let Ok(entries) = std::fs::read_dir(chapter_dir(output_dir, manga, chapter)) else {
+        return false;
+    };
+
+    let saved_pages = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            is_numbered_page(Path::new(&entry.file_name()))
+                && entry.file_type().is_ok_and(|file_type| file_type.is_file())
+        })
+        .count();
+    let expected_pages = chapter
+        .page_count
+        .or((!chapter.pages.is_empty()).then_some(chapter.pages.len()))
+        .unwrap_or(1);
+
+    saved_pages >= expected_pages.max(1)
+}
 
-    // Get the path part
-    let path = clean_url.split('/').last()?;
+/// Whether a file is a page saved under a page number, like `001.jpg`
+fn is_numbered_page(path: &Path) -> bool {
+    let numbered = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .is_some_and(|stem| !stem.is_empty() && stem.bytes().all(|b| b.is_ascii_digit()));
+    numbered && path.extension().is_some_and(|ext| ext != PART_EXTENSION)
+}
+
+/// Checks [`chapter_is_downloaded`] for each of the given chapters.
+///
+/// # Returns
+///
+/// One flag per chapter, in the same order as `chapters`.
+pub fn downloaded_chapters(output_dir: &Path, manga: &Manga, chapters: &[Chapter]) -> Vec<bool> {
+    chapters
+        .iter()
+        .map(|chapter| chapter_is_downloaded(output_dir, manga, chapter))
+        .collect()
+}
+
+/// Strategy for naming downloaded page files within a chapter directory.
+///
+/// The file extension is always determined separately (from the target
+/// [`ImageFormat`](crate::types::ImageFormat) or the page URL); the strategy
+/// only controls the file stem.
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::download::NamingStrategy;
+///
+/// let url = "https://cdn.example.com/data/x1-abc.png?token=1";
+/// assert_eq!(NamingStrategy::Indexed.file_name(7, url, "png"), "7.png");
+/// assert_eq!(NamingStrategy::ZeroPadded(4).file_name(7, url, "png"), "0007.png");
+/// assert_eq!(NamingStrategy::PreserveOriginal.file_name(7, url, "jpg"), "x1-abc.jpg");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NamingStrategy {
+    /// Plain page numbers starting at 1 (`1.jpg`, `2.jpg`, ...)
+    Indexed,
+    /// The basename of the page URL, passed through [`sanitize_filename`]
+    ///
+    /// When a chapter download meets a basename an earlier page of the chapter
+    /// already used, the later page is saved with its zero-padded page number
+    /// as a prefix (`002-1.jpg`) instead of overwriting the earlier one.
+    PreserveOriginal,
+    /// Page numbers left-padded with zeros to the given width (`001.jpg` for width 3)
+    ZeroPadded(usize),
+    /// Page numbers zero-padded to fit the chapter's page count, see [`page_number_width`]
+    AutoPadded,
+}
 
-    // Extract extension
-    if let Some(dot_pos) = path.rfind('.') {
-        let ext = &path[dot_pos + 1..];
-        if !ext.is_empty() && ext.len() <= 10 {
-            return Some(ext.to_lowercase());
+impl Default for NamingStrategy {
+    /// Zero padding wide enough for the chapter, matching [`Source::download_chapter`](crate::Source::download_chapter).
+    fn default() -> Self {
+        NamingStrategy::AutoPadded
+    }
+}
+
+/// Minimum number of digits [`NamingStrategy::AutoPadded`] pads page numbers to.
+pub const MIN_PAGE_NUMBER_WIDTH: usize = 3;
+
+/// Number of digits needed for page file names of a chapter to sort lexically.
+///
+/// # Parameters
+///
+/// * `total_pages` - The number of pages in the chapter
+///
+/// # Returns
+///
+/// The digit count of `total_pages`, but at least [`MIN_PAGE_NUMBER_WIDTH`].
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::download::page_number_width;
+///
+/// assert_eq!(page_number_width(5), 3);
+/// assert_eq!(page_number_width(1500), 4);
+/// ```
+pub fn page_number_width(total_pages: usize) -> usize {
+    total_pages.to_string().len().max(MIN_PAGE_NUMBER_WIDTH)
+}
+
+impl NamingStrategy {
+    /// Fixes the padding of [`AutoPadded`](NamingStrategy::AutoPadded) for a chapter.
+    ///
+    /// Other strategies are returned unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// * `total_pages` - The number of pages in the chapter
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::download::NamingStrategy;
+    ///
+    /// let naming = NamingStrategy::AutoPadded.for_page_count(1500);
+    /// assert_eq!(naming, NamingStrategy::ZeroPadded(4));
+    /// assert_eq!(naming.file_name(7, "https://example.com/7.png", "png"), "0007.png");
+    /// ```
+    pub fn for_page_count(self, total_pages: usize) -> Self {
+        match self {
+            NamingStrategy::AutoPadded => {
+                NamingStrategy::ZeroPadded(page_number_width(total_pages))
+            }
+            other => other,
         }
     }
 
-    None
+    /// Builds the file name for a single page.
+    ///
+    /// # Parameters
+    ///
+    /// * `page_num` - The 1-based page number
+    /// * `url` - The URL the page was downloaded from
+    /// * `extension` - The extension to use for the saved file (without the dot)
+    ///
+    /// # Returns
+    ///
+    /// The file name, including the extension. [`PreserveOriginal`](NamingStrategy::PreserveOriginal)
+    /// falls back to the page number when the URL has no usable basename.
+    /// [`AutoPadded`](NamingStrategy::AutoPadded) pads to [`MIN_PAGE_NUMBER_WIDTH`]
+    /// unless it was resolved with [`for_page_count()`](NamingStrategy::for_page_count) first.
+    pub fn file_name(&self, page_num: usize, url: &str, extension: &str) -> String {
+        let stem = match self {
+            NamingStrategy::Indexed => page_num.to_string(),
+            NamingStrategy::ZeroPadded(width) => format!("{:0width$}", page_num, width = *width),
+            NamingStrategy::AutoPadded => {
+                format!("{:0width$}", page_num, width = MIN_PAGE_NUMBER_WIDTH)
+            }
+            NamingStrategy::PreserveOriginal => {
+                let path = url.split(['?', '#']).next().unwrap_or_default();
+                let basename = path.rsplit('/').next().unwrap_or_default();
+                let stem = match basename.rfind('.') {
+                    Some(dot_pos) => &basename[..dot_pos],
+                    None => basename,
+                };
+
+                if stem.trim().is_empty() {
+                    page_num.to_string()
+                } else {
+                    sanitize_filename(stem)
+                }
+            }
+        };
+
+        format!("{}.{}", stem, extension)
+    }
+}
+
+/// A downloaded page that failed validation and was not saved.
+///
+/// Collected in [`DownloadReport::invalid_pages`] by
+/// [`Source::download_chapter_validated`](crate::Source::download_chapter_validated).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidPage {
+    /// The 1-based page number
+    pub page: usize,
+    /// The URL the page was downloaded from
+    pub url: String,
+    /// Why the page was rejected
+    pub reason: String,
+}
+
+/// Outcome of a validated chapter download.
+///
+/// Invalid pages don't fail the download; they are skipped and listed here so
+/// callers can retry them or warn the user.
+#[derive(Debug, Clone)]
+pub struct DownloadReport {
+    /// The chapter directory the pages were saved to
+    pub chapter_dir: PathBuf,
+    /// Number of pages written to disk
+    pub saved_pages: usize,
+    /// Pages that were rejected, in page order
+    pub invalid_pages: Vec<InvalidPage>,
+}
+
+/// Default minimum width and height, in pixels, for a page to count as valid.
+///
+/// Small enough for narrow strips, large enough to reject tracking pixels
+/// and placeholder images.
+#[cfg(feature = "conversion")]
+pub const MIN_PAGE_DIMENSION: u32 = 50;
+
+/// Checks that downloaded page data is an image of at least the given size.
+///
+/// Catches sources that answer with `200 OK` but serve an HTML error page or a
+/// 1×1 tracking pixel instead of the page. The data is fully decoded, so
+/// truncated images are rejected too.
+///
+/// # Parameters
+///
+/// * `data` - The raw page data
+/// * `min_dimension` - Minimum width and height in pixels
+///
+/// # Returns
+///
+/// The image's width and height.
+///
+/// # Errors
+///
+/// * [`Error::Parse`] - If the data isn't a decodable image or is too small
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::download::validate_page_image;
+///
+/// assert!(validate_page_image(b"<html>Not found</html>", 50).is_err());
+/// ```
+#[cfg(feature = "conversion")]
+pub fn validate_page_image(data: &[u8], min_dimension: u32) -> Result<(u32, u32)> {
+    let image = image::load_from_memory(data)
+        .map_err(|e| Error::parse(format!("Not a valid image: {}", e)))?;
+
+    let (width, height) = (image.width(), image.height());
+    if width < min_dimension || height < min_dimension {
+        return Err(Error::parse(format!(
+            "Image is {}x{}, below the minimum of {}x{}",
+            width, height, min_dimension, min_dimension
+        )));
+    }
+
+    Ok((width, height))
 }
 
 #[cfg(test)]
@@ -196,4 +727,130 @@ mod tests {
         assert_eq!(extract_extension("https://example.com/image"), None);
         assert_eq!(extract_extension("https://example.com/image."), None);
     }
+
+    #[test]
+    fn test_extract_extension_format_param() {
+        assert_eq!(
+            extract_extension("https://cdn.example.com/p/123?format=webp"),
+            Some("webp".to_string())
+        );
+        assert_eq!(
+            extract_extension("https://cdn.example.com/p/123?w=800&Format=AVIF#top"),
+            Some("avif".to_string())
+        );
+        // A known path extension wins over the parameter
+        assert_eq!(
+            extract_extension("https://cdn.example.com/p/123.png?format=webp"),
+            Some("png".to_string())
+        );
+        assert_eq!(
+            extract_extension("https://cdn.example.com/p/123?format=json"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_extract_extension_double_extension() {
+        assert_eq!(
+            extract_extension("https://uploads.example.org/data/hash/x1-abc.png.jpg"),
+            Some("jpg".to_string())
+        );
+        assert_eq!(
+            extract_extension("https://example.com/image.jpg.php?format=png"),
+            Some("png".to_string())
+        );
+        assert_eq!(extract_extension("https://example.com/page.html"), None);
+    }
+
+    #[test]
+    fn test_auto_padding_fits_page_count() {
+        let url = "https://example.com/page.png";
+
+        let small = NamingStrategy::AutoPadded.for_page_count(5);
+        assert_eq!(small, NamingStrategy::ZeroPadded(3));
+        assert_eq!(small.file_name(5, url, "png"), "005.png");
+
+        let webtoon = NamingStrategy::AutoPadded.for_page_count(1500);
+        assert_eq!(webtoon, NamingStrategy::ZeroPadded(4));
+        let names: Vec<String> = [1, 999, 1000, 1500]
+            .into_iter()
+            .map(|page| webtoon.file_name(page, url, "png"))
+            .collect();
+        assert_eq!(names, ["0001.png", "0999.png", "1000.png", "1500.png"]);
+        assert!(names.is_sorted());
+
+        // Explicit strategies are left alone
+        assert_eq!(
+            NamingStrategy::ZeroPadded(2).for_page_count(1500),
+            NamingStrategy::ZeroPadded(2)
+        );
+        assert_eq!(
+            NamingStrategy::Indexed.for_page_count(1500),
+            NamingStrategy::Indexed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_interrupted_write_leaves_no_page() {
+        use std::pin::Pin;
+        use std::task::{Context, Poll};
+        use tokio::io::{AsyncRead, AsyncReadExt, ReadBuf};
+
+        // Stands in for a connection that drops after part of the page arrived
+        struct Interrupted;
+
+        impl AsyncRead for Interrupted {
+            fn poll_read(
+                self: Pin<&mut Self>,
+                _cx: &mut Context<'_>,
+                _buf: &mut ReadBuf<'_>,
+            ) -> Poll<std::io::Result<()>> {
+                Poll::Ready(Err(std::io::Error::other("connection lost")))
+            }
+        }
+
+        let dir = std::env::temp_dir().join(format!("tosho-atomic-{}", std::process::id()));
+        fs::create_dir_all(&dir).await.unwrap();
+        let page = dir.join("001.jpg");
+
+        let result = write_atomic_from(&page, (&b"partial image"[..]).chain(Interrupted)).await;
+        assert!(matches!(result, Err(Error::Io(_))));
+
+        let mut entries = fs::read_dir(&dir).await.unwrap();
+        while let Some(entry) = entries.next_entry().await.unwrap() {
+            assert!(
+                entry
+                    .path()
+                    .extension()
+                    .is_some_and(|ext| ext == PART_EXTENSION),
+                "unexpected file {:?}",
+                entry.path()
+            );
+        }
+        assert!(!page.exists());
+
+        // A complete write replaces the page in one step
+        write_file_atomic(&page, b"full image").await.unwrap();
+        assert_eq!(fs::read(&page).await.unwrap(), b"full image");
+        assert!(!dir.join("001.jpg.part").exists());
+
+        fs::remove_dir_all(&dir).await.unwrap();
+    }
+
+    #[test]
+    fn test_naming_strategy_preserve_original() {
+        let strategy = NamingStrategy::PreserveOriginal;
+        assert_eq!(
+            strategy.file_name(1, "https://example.com/a/page:01.png#frag", "png"),
+            "page_01.png"
+        );
+        assert_eq!(
+            strategy.file_name(2, "https://example.com/a/", "jpg"),
+            "2.jpg"
+        );
+        assert_eq!(
+            strategy.file_name(3, "https://example.com/archive.tar.gz", "webp"),
+            "archive.tar.webp"
+        );
+    }
 }
@@ -72,8 +72,28 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// * [`RateLimit`](Error::RateLimit) - Rate limiting responses
 /// * [`Io`](Error::Io) - File system and IO errors
 /// * [`Json`](Error::Json) - JSON serialization errors
+/// * [`Conversion`](Error::Conversion) - Image and archive conversion errors
 /// * [`Other`](Error::Other) - Generic error messages
+///
+/// # Compatibility
+///
+/// `Error` is `#[non_exhaustive]`: new variants may be added in minor releases.
+/// Code matching on it outside this crate needs a wildcard arm, which then
+/// covers any variant added later.
+///
+/// ```rust
+/// use tosho::Error;
+///
+/// fn describe(error: &Error) -> &'static str {
+///     match error {
+///         Error::NotFound(_) => "not found",
+///         Error::RateLimit { .. } => "slow down",
+///         _ => "something else went wrong",
+///     }
+/// }
+/// ```
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// Network-related errors from HTTP operations.
     ///
@@ -118,6 +138,9 @@ pub enum Error {
     ///
     /// * `src` - The identifier of the source that encountered the error
     /// * `message` - Descriptive error message explaining what went wrong
+    /// * `status` - The HTTP status code, if the error came from an HTTP response
+    /// * `cause` - The lower-level error this one wraps, exposed through
+    ///   [`std::error::Error::source()`]
     ///
     /// # Examples
     ///
@@ -126,9 +149,21 @@ pub enum Error {
     ///
     /// let error = Error::source("mangadex", "API rate limit exceeded");
     /// let error = Error::source("madara-site", "Invalid chapter ID");
+    ///
+    /// // React to specific HTTP statuses
+    /// let error = Error::source_status("mangadex", 503);
+    /// if let Error::Source { status: Some(503), .. } = error {
+    ///     // Service unavailable, try again later
+    /// }
     /// ```
-    #[error("Source error [{src}]: {message}")]
-    Source { src: String, message: String },
+    #[error("Source error [{src}]{}: {message}", format_status(.status))]
+    Source {
+        src: String,
+        message: String,
+        status: Option<u16>,
+        #[source]
+        cause: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
 
     /// Resource not found errors.
     ///
@@ -187,16 +222,41 @@ pub enum Error {
     /// Image processing errors.
     ///
     /// This variant wraps errors from image processing operations, such as
-    /// converting images.
+    /// converting images. Only available with the `conversion` feature.
+    #[cfg(feature = "conversion")]
     #[error("Image error: {0}")]
     Image(#[from] image::ImageError),
 
+    /// Database errors.
+    ///
+    /// This variant wraps errors from sqlx when storing or loading data with
+    /// the helpers in [`crate::db`]. Only available with the `db-sqlite` feature.
+    #[cfg(feature = "db-sqlite")]
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
     /// Join errors.
     ///
     /// This variant wraps errors from tokio tasks.
     #[error("Join error: {0}")]
     Join(#[from] tokio::task::JoinError),
 
+    /// Conversion errors.
+    ///
+    /// This variant is used when converting downloaded content into another
+    /// format fails, giving conversion code a way to report failures through
+    /// the crate-wide [`Result`] type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::Error;
+    ///
+    /// let error = Error::conversion("No images found in chapter directory");
+    /// ```
+    #[error("Conversion error: {0}")]
+    Conversion(String),
+
     /// Generic error messages.
     ///
     /// This variant is used for errors that don't fit into other specific
@@ -257,6 +317,77 @@ impl Error {
         Error::Source {
             src: src.into(),
             message: msg.into(),
+            status: None,
+            cause: None,
+        }
+    }
+
+    /// Creates a source-specific error that wraps an underlying cause.
+    ///
+    /// Like [`Error::source`], but keeps the original error so it can be
+    /// reached through [`std::error::Error::source()`], e.g. when printing an
+    /// error chain or a backtrace.
+    ///
+    /// # Parameters
+    ///
+    /// * `src` - The identifier of the source that encountered the error
+    /// * `msg` - A message describing what went wrong
+    /// * `cause` - The lower-level error that caused this one
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::error::Error as _;
+    /// use tosho::Error;
+    ///
+    /// let cause = "not a number".parse::<u32>().unwrap_err();
+    /// let error = Error::source_with("madara-site", "Invalid chapter number", cause);
+    ///
+    /// assert_eq!(error.source().unwrap().to_string(), "invalid digit found in string");
+    /// ```
+    pub fn source_with(
+        src: impl Into<String>,
+        msg: impl Into<String>,
+        cause: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        Error::Source {
+            src: src.into(),
+            message: msg.into(),
+            status: None,
+            cause: Some(cause.into()),
+        }
+    }
+
+    /// Creates a source-specific error for an unsuccessful HTTP response.
+    ///
+    /// The message is set to the canonical reason phrase of the status code
+    /// (e.g. `"Service Unavailable"` for 503), and the status is kept in the
+    /// `status` field so callers can react to it programmatically.
+    ///
+    /// # Parameters
+    ///
+    /// * `src` - The identifier of the source that encountered the error
+    /// * `status` - The HTTP status code of the response
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::Error;
+    ///
+    /// let error = Error::source_status("mangadex", 404);
+    /// assert_eq!(error.to_string(), "Source error [mangadex] (HTTP 404): Not Found");
+    /// ```
+    pub fn source_status(src: impl Into<String>, status: u16) -> Self {
+        let message = reqwest::StatusCode::from_u16(status)
+            .ok()
+            .and_then(|code| code.canonical_reason())
+            .unwrap_or("Unknown status");
+
+        Error::Source {
+            src: src.into(),
+            message: message.to_string(),
+            status: Some(status),
+            cause: None,
         }
     }
 
@@ -304,4 +435,58 @@ impl Error {
     pub fn rate_limit(retry_after: Option<u64>) -> Self {
         Error::RateLimit { retry_after }
     }
+
+    /// Creates a conversion error with the given message.
+    ///
+    /// This is a convenience method for creating [`Error::Conversion`] variants
+    /// with a descriptive message about what conversion step failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` - A message describing the conversion error
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::Error;
+    ///
+    /// let error = Error::conversion("Unsupported output format");
+    /// ```
+    pub fn conversion(msg: impl Into<String>) -> Self {
+        Error::Conversion(msg.into())
+    }
+
+    /// Returns `true` if the same request may succeed when tried again later.
+    ///
+    /// Network failures, rate limits and server-side HTTP errors (5xx and
+    /// 408) are considered transient. Everything else, such as parse errors
+    /// or missing resources, fails the same way on every attempt.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::Error;
+    ///
+    /// assert!(Error::rate_limit(None).is_retryable());
+    /// assert!(Error::source_status("mangadex", 503).is_retryable());
+    /// assert!(!Error::source_status("mangadex", 404).is_retryable());
+    /// assert!(!Error::not_found("Chapter").is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Network(_) | Error::RateLimit { .. } => true,
+            Error::Source {
+                status: Some(status),
+                ..
+            } => *status == 408 || *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+/// Formats the optional HTTP status of [`Error::Source`] for display.
+fn format_status(status: &Option<u16>) -> String {
+    status
+        .map(|code| format!(" (HTTP {})", code))
+        .unwrap_or_default()
 }
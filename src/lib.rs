@@ -12,7 +12,7 @@
 //! - **Fluent Builder Pattern**: Chain search parameters and execution strategies elegantly
 //! - **Async/Await Support**: Built on tokio for high-performance concurrent operations
 //! - **Rate Limiting**: Per-source rate limiting to respect website policies
-//! - **Parallel Processing**: Uses rayon for CPU-intensive parsing operations
+//! - **Parallel Processing**: Uses rayon for CPU-intensive parsing operations (`parallel` feature)
 //! - **Robust Error Handling**: Comprehensive error types with detailed context
 //! - **Result Processing**: Built-in deduplication, sorting, and filtering capabilities
 //!
@@ -129,6 +129,8 @@
 //! # }
 //! ```
 
+#[cfg(feature = "db-sqlite")]
+pub mod db;
 pub mod download;
 pub mod error;
 pub mod net;
@@ -148,23 +150,23 @@ pub mod types;
 /// use tosho::prelude::*;
 ///
 /// // Now you have access to:
-/// // - Sources, Source trait
-/// // - SearchBuilder, SearchResultExt
+/// // - Sources, Source trait, Capability
+/// // - SearchBuilder, SearchResult, SearchResultExt, DedupeMode
 /// // - Manga, Chapter, SearchParams, SortOrder
 /// // - Download utilities
 /// ```
 pub mod prelude {
     pub use crate::{
-        download::{download_file, extract_extension, sanitize_filename},
-        search::{SearchBuilder, SearchResultExt},
-        source::{Source, Sources},
+        download::{NamingStrategy, download_file, extract_extension, sanitize_filename},
+        search::{DedupeMode, SearchBuilder, SearchResult, SearchResultExt},
+        source::{Capability, Source, Sources},
         types::{Chapter, Manga, SearchParams, SortOrder},
     };
 }
 
 // Re-export main types at crate root for direct access
-pub use download::{download_file, extract_extension, sanitize_filename};
+pub use download::{NamingStrategy, download_file, extract_extension, sanitize_filename};
 pub use error::{Error, Result};
-pub use search::{SearchBuilder, SearchResultExt};
-pub use source::{Source, Sources};
+pub use search::{DedupeMode, SearchBuilder, SearchResult, SearchResultExt};
+pub use source::{Capability, Source, Sources};
 pub use types::{Chapter, Manga, SearchParams, SortOrder};
@@ -2,7 +2,7 @@
 //!
 //! This module provides convenient functions for parsing HTML content from manga websites.
 //! It uses the `scraper` crate for CSS selector-based parsing and `rayon` for parallel
-//! processing of multiple elements.
+//! processing of multiple elements (with the `parallel` feature).
 //!
 //! # Examples
 //!
@@ -23,8 +23,10 @@
 //! let cover_url = html::select_attr(&document, "img", "src").unwrap();
 //! ```
 
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
-use scraper::{Html, Selector};
+use scraper::{ElementRef, Html, Selector};
+use std::collections::HashMap;
 
 /// Parses an HTML document from a string.
 ///
@@ -76,11 +78,9 @@ pub fn parse(html: &str) -> Html {
 /// assert_eq!(title, Some("One Piece".to_string()));
 /// ```
 pub fn select_text(html: &Html, selector: &str) -> Option<String> {
-    Selector::parse(selector).ok().and_then(|sel| {
-        html.select(&sel)
-            .next()
-            .map(|el| el.text().collect::<String>().trim().to_string())
-    })
+    Selector::parse(selector)
+        .ok()
+        .and_then(|sel| first_text(html, &sel))
 }
 
 /// Extracts an attribute value from the first element matching a CSS selector.
@@ -109,11 +109,9 @@ pub fn select_text(html: &Html, selector: &str) -> Option<String> {
 /// assert_eq!(src, Some("cover.jpg".to_string()));
 /// ```
 pub fn select_attr(html: &Html, selector: &str, attr: &str) -> Option<String> {
-    Selector::parse(selector).ok().and_then(|sel| {
-        html.select(&sel)
-            .next()
-            .and_then(|el| el.value().attr(attr).map(String::from))
-    })
+    Selector::parse(selector)
+        .ok()
+        .and_then(|sel| first_attr(html, &sel, attr))
 }
 
 /// Extracts text content from all elements matching a CSS selector.
@@ -149,11 +147,7 @@ pub fn select_attr(html: &Html, selector: &str, attr: &str) -> Option<String> {
 pub fn select_all_text(html: &Html, selector: &str) -> Vec<String> {
     Selector::parse(selector)
         .ok()
-        .map(|sel| {
-            html.select(&sel)
-                .map(|el| el.text().collect::<String>().trim().to_string())
-                .collect()
-        })
+        .map(|sel| all_text(html, &sel))
         .unwrap_or_default()
 }
 
@@ -192,19 +186,185 @@ pub fn select_all_text(html: &Html, selector: &str) -> Vec<String> {
 pub fn select_all_attr(html: &Html, selector: &str, attr: &str) -> Vec<String> {
     Selector::parse(selector)
         .ok()
-        .map(|sel| {
-            html.select(&sel)
-                .filter_map(|el| el.value().attr(attr).map(String::from))
-                .collect()
-        })
+        .map(|sel| all_attr(html, &sel, attr))
         .unwrap_or_default()
 }
 
+/// Extracts and joins the text content of all elements matching a CSS selector.
+///
+/// Useful for content split across several elements, such as descriptions
+/// spread over multiple `<p>` tags. Elements without any text are skipped so
+/// they don't leave doubled separators behind.
+///
+/// # Parameters
+///
+/// * `html` - The parsed HTML document
+/// * `selector` - CSS selector string
+/// * `separator` - String placed between the text of consecutive elements
+///
+/// # Returns
+///
+/// The joined text of all matching elements, or `None` if no elements match
+/// or the selector is invalid.
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::net::html;
+///
+/// let document = html::parse(r#"
+///     <div class="summary">
+///         <p>A pirate sets sail.</p>
+///         <p>He wants to find the One Piece.</p>
+///     </div>
+/// "#);
+/// let description = html::select_text_joined(&document, ".summary p", " ");
+/// assert_eq!(
+///     description.as_deref(),
+///     Some("A pirate sets sail. He wants to find the One Piece.")
+/// );
+/// ```
+pub fn select_text_joined(html: &Html, selector: &str, separator: &str) -> Option<String> {
+    let sel = Selector::parse(selector).ok()?;
+    let mut elements = html.select(&sel).peekable();
+    elements.peek()?;
+
+    Some(
+        elements
+            .map(element_text)
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join(separator),
+    )
+}
+
+/// A set of CSS selectors parsed once and reused across documents.
+///
+/// The free functions in this module parse their selector on every call. When a
+/// source extracts the same fields from hundreds of elements, compiling the
+/// selectors up front avoids that repeated work. Selectors are looked up by the
+/// exact string they were compiled from, and the extraction methods mirror
+/// [`select_text()`], [`select_attr()`], [`select_all_text()`] and
+/// [`select_all_attr()`].
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::net::html::{self, CompiledSelectors};
+///
+/// let selectors = CompiledSelectors::new([".title", "img"]).unwrap();
+///
+/// let document = html::parse(r#"<h3 class="title">One Piece</h3><img src="cover.jpg">"#);
+/// assert_eq!(selectors.select_text(&document, ".title"), Some("One Piece".to_string()));
+/// assert_eq!(selectors.select_attr(&document, "img", "src"), Some("cover.jpg".to_string()));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CompiledSelectors {
+    selectors: HashMap<String, Selector>,
+}
+
+impl CompiledSelectors {
+    /// Parses all given CSS selectors.
+    ///
+    /// # Parameters
+    ///
+    /// * `selectors` - The CSS selector strings to compile
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::Parse`](crate::Error::Parse) - If any selector is invalid
+    pub fn new<'a>(selectors: impl IntoIterator<Item = &'a str>) -> crate::Result<Self> {
+        let mut compiled = Self::default();
+        for selector in selectors {
+            compiled.insert(selector)?;
+        }
+        Ok(compiled)
+    }
+
+    /// Parses and adds a single CSS selector. Already compiled selectors are kept as-is.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::Parse`](crate::Error::Parse) - If the selector is invalid
+    pub fn insert(&mut self, selector: &str) -> crate::Result<()> {
+        if !self.selectors.contains_key(selector) {
+            let parsed = Selector::parse(selector).map_err(|e| {
+                crate::Error::parse(format!("Invalid selector '{}': {}", selector, e))
+            })?;
+            self.selectors.insert(selector.to_string(), parsed);
+        }
+        Ok(())
+    }
+
+    /// Returns the compiled selector for the given selector string, if present.
+    pub fn get(&self, selector: &str) -> Option<&Selector> {
+        self.selectors.get(selector)
+    }
+
+    /// Like [`select_text()`], using the compiled selector.
+    ///
+    /// Returns `None` if the selector was not compiled into this set.
+    pub fn select_text(&self, html: &Html, selector: &str) -> Option<String> {
+        self.get(selector).and_then(|sel| first_text(html, sel))
+    }
+
+    /// Like [`select_attr()`], using the compiled selector.
+    ///
+    /// Returns `None` if the selector was not compiled into this set.
+    pub fn select_attr(&self, html: &Html, selector: &str, attr: &str) -> Option<String> {
+        self.get(selector)
+            .and_then(|sel| first_attr(html, sel, attr))
+    }
+
+    /// Like [`select_all_text()`], using the compiled selector.
+    ///
+    /// Returns an empty vector if the selector was not compiled into this set.
+    pub fn select_all_text(&self, html: &Html, selector: &str) -> Vec<String> {
+        self.get(selector)
+            .map(|sel| all_text(html, sel))
+            .unwrap_or_default()
+    }
+
+    /// Like [`select_all_attr()`], using the compiled selector.
+    ///
+    /// Returns an empty vector if the selector was not compiled into this set.
+    pub fn select_all_attr(&self, html: &Html, selector: &str, attr: &str) -> Vec<String> {
+        self.get(selector)
+            .map(|sel| all_attr(html, sel, attr))
+            .unwrap_or_default()
+    }
+}
+
+fn element_text(el: ElementRef) -> String {
+    el.text().collect::<String>().trim().to_string()
+}
+
+fn first_text(html: &Html, sel: &Selector) -> Option<String> {
+    html.select(sel).next().map(element_text)
+}
+
+fn first_attr(html: &Html, sel: &Selector, attr: &str) -> Option<String> {
+    html.select(sel)
+        .next()
+        .and_then(|el| el.value().attr(attr).map(String::from))
+}
+
+fn all_text(html: &Html, sel: &Selector) -> Vec<String> {
+    html.select(sel).map(element_text).collect()
+}
+
+fn all_attr(html: &Html, sel: &Selector, attr: &str) -> Vec<String> {
+    html.select(sel)
+        .filter_map(|el| el.value().attr(attr).map(String::from))
+        .collect()
+}
+
 /// Parses manga items from HTML in parallel using rayon.
 ///
 /// This function is optimized for parsing large lists of manga items by processing
 /// them in parallel. It finds all elements matching the selector, converts them to
 /// HTML strings, and processes them concurrently using rayon's parallel iterator.
+/// Without the `parallel` feature the items are parsed sequentially instead.
 ///
 /// # Type Parameters
 ///
@@ -254,6 +414,8 @@ pub fn select_all_attr(html: &Html, selector: &str, attr: &str) -> Vec<String> {
 /// #       cover_url: None,
 /// #       description: None,
 /// #       tags: vec![],
+/// #       content_rating: None,
+/// #       available_languages: vec![],
 ///     })
 /// });
 /// ```
@@ -263,7 +425,8 @@ pub fn select_all_attr(html: &Html, selector: &str, attr: &str) -> Vec<String> {
 /// This function uses rayon's parallel iterators to process manga items concurrently,
 /// which can significantly improve performance when parsing large lists of items.
 /// The elements are first collected into HTML strings to avoid borrowing issues
-/// with parallel processing.
+/// with parallel processing. Lists with fewer than [`PARALLEL_THRESHOLD`] items
+/// are parsed sequentially, where the thread-pool overhead would outweigh the gain.
 pub fn parse_manga_items<F>(html: &Html, selector: &str, parser: F) -> Vec<crate::Manga>
 where
     F: Fn(scraper::ElementRef) -> Option<crate::Manga> + Sync,
@@ -273,16 +436,157 @@ where
         .map(|sel| {
             // Convert ElementRef to HTML strings which can be processed in parallel
             let elements: Vec<String> = html.select(&sel).map(|el| el.html()).collect();
+            let parallel = elements.len() >= PARALLEL_THRESHOLD;
 
-            // Parse in parallel with rayon
-            elements
-                .into_par_iter()
-                .filter_map(|html_str| {
-                    let doc = Html::parse_fragment(&html_str);
-                    let element = doc.root_element();
-                    parser(element)
-                })
-                .collect()
+            parse_elements(elements, &parser, parallel)
         })
         .unwrap_or_default()
 }
+
+/// Minimum number of items for [`parse_manga_items()`] to parse in parallel.
+pub const PARALLEL_THRESHOLD: usize = 8;
+
+/// Runs the item parser over pre-rendered element HTML, in parallel if requested
+/// and the `parallel` feature is enabled. Output order matches input order.
+fn parse_elements<F>(elements: Vec<String>, parser: &F, parallel: bool) -> Vec<crate::Manga>
+where
+    F: Fn(scraper::ElementRef) -> Option<crate::Manga> + Sync,
+{
+    let parse_item = |html_str: String| {
+        let doc = Html::parse_fragment(&html_str);
+        let element = doc.root_element();
+        parser(element)
+    };
+
+    // Parse in parallel with rayon
+    #[cfg(feature = "parallel")]
+    if parallel {
+        return elements.into_par_iter().filter_map(parse_item).collect();
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    let _ = parallel;
+
+    elements.into_iter().filter_map(parse_item).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_title(element: scraper::ElementRef) -> Option<crate::Manga> {
+        let fragment = parse(&element.html());
+        let title = select_text(&fragment, "h3")?;
+
+        Some(crate::Manga {
+            id: title.to_lowercase().replace(' ', "-"),
+            url: select_attr(&fragment, "a", "href"),
+            title,
+            authors: select_all_text(&fragment, ".author"),
+            description: None,
+            tags: vec![],
+            content_rating: None,
+            available_languages: vec![],
+            cover_url: None,
+            source_id: "test".to_string(),
+        })
+    }
+
+    #[test]
+    fn test_compiled_selectors_match_free_functions() {
+        let document = parse(
+            r#"
+            <div class="manga">
+                <h3 class="title"> One Piece </h3>
+                <img class="cover" src="cover.jpg">
+                <span class="tag">Action</span>
+                <span class="tag">Adventure</span>
+                <a href="/chapter/1">1</a><a href="/chapter/2">2</a>
+            </div>
+            "#,
+        );
+        let selectors = CompiledSelectors::new([".title", ".cover", ".tag", "a"]).unwrap();
+
+        assert_eq!(
+            selectors.select_text(&document, ".title"),
+            select_text(&document, ".title")
+        );
+        assert_eq!(
+            selectors.select_attr(&document, ".cover", "src"),
+            select_attr(&document, ".cover", "src")
+        );
+        assert_eq!(
+            selectors.select_all_text(&document, ".tag"),
+            select_all_text(&document, ".tag")
+        );
+        assert_eq!(
+            selectors.select_all_attr(&document, "a", "href"),
+            select_all_attr(&document, "a", "href")
+        );
+
+        // Selectors that weren't compiled find nothing, invalid ones are rejected
+        assert_eq!(selectors.select_text(&document, "h3"), None);
+        assert!(CompiledSelectors::new(["div[["]).is_err());
+    }
+
+    #[test]
+    fn test_select_text_joined() {
+        let document = parse(
+            r#"
+            <div class="description">
+                <p> First paragraph. </p>
+                <p></p>
+                <p>Second <em>paragraph</em>.</p>
+            </div>
+            "#,
+        );
+
+        assert_eq!(
+            select_text_joined(&document, ".description p", "\n").as_deref(),
+            Some("First paragraph.\nSecond paragraph.")
+        );
+        assert_eq!(select_text_joined(&document, ".summary p", " "), None);
+    }
+
+    #[test]
+    fn test_sequential_and_parallel_parsing_match() {
+        let elements: Vec<String> = (0..PARALLEL_THRESHOLD * 3)
+            .map(|i| {
+                if i % 5 == 0 {
+                    // Items the parser rejects must be dropped on both paths
+                    "<div class=\"item\"><span>No title</span></div>".to_string()
+                } else {
+                    format!(
+                        "<div class=\"item\"><h3>Manga {i}</h3><a href=\"/m/{i}\">Link</a><span class=\"author\">Author {i}</span></div>"
+                    )
+                }
+            })
+            .collect();
+
+        let sequential = parse_elements(elements.clone(), &parse_title, false);
+        let parallel = parse_elements(elements, &parse_title, true);
+
+        assert_eq!(sequential.len(), PARALLEL_THRESHOLD * 3 - 5);
+        assert_eq!(
+            serde_json::to_value(&sequential).unwrap(),
+            serde_json::to_value(&parallel).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_manga_items_preserves_order_on_both_paths() {
+        for count in [PARALLEL_THRESHOLD - 1, PARALLEL_THRESHOLD + 1] {
+            let body: String = (0..count)
+                .map(|i| format!("<div class=\"item\"><h3>Manga {i}</h3></div>"))
+                .collect();
+            let document = parse(&body);
+
+            let titles: Vec<String> = parse_manga_items(&document, ".item", parse_title)
+                .into_iter()
+                .map(|m| m.title)
+                .collect();
+            let expected: Vec<String> = (0..count).map(|i| format!("Manga {i}")).collect();
+            assert_eq!(titles, expected);
+        }
+    }
+}
@@ -24,9 +24,14 @@
 //! let title = json::extract_path(&data, "manga.title").unwrap();
 //! let chapters = json::extract_array(&data, "manga.chapters");
 //! ```
+//!
+//! For large responses, [`stream_array`] deserializes the elements of an array
+//! one at a time instead of building the whole document in memory.
 
 use serde::de::DeserializeOwned;
 use serde_json::Value;
+use serde_json::value::RawValue;
+use std::collections::HashMap;
 
 /// Extracts a value from nested JSON using dot notation.
 ///
@@ -175,3 +180,94 @@ pub fn extract_array(json: &Value, path: &str) -> Vec<Value> {
         .and_then(|v| v.as_array().cloned())
         .unwrap_or_default()
 }
+
+/// Deserializes the elements of a JSON array one at a time.
+///
+/// Unlike parsing into a [`Value`] or a `Vec<T>`, only the element currently
+/// being deserialized is fully built in memory. The rest of the document is
+/// only scanned for element boundaries, so large responses, such as chapter
+/// feeds fetched with a high limit, stay cheap. Each element is deserialized
+/// separately, so an element that doesn't match `T` yields an error without
+/// ending the iteration.
+///
+/// # Type Parameters
+///
+/// * `T` - The type to deserialize each element into
+///
+/// # Parameters
+///
+/// * `bytes` - The raw JSON document
+/// * `pointer` - A [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901)
+///   to the array, e.g. `"/data"`, or `""` if the document itself is the array
+///
+/// # Returns
+///
+/// An iterator over the deserialized elements, in document order.
+///
+/// # Errors
+///
+/// * [`Error::Json`](crate::Error::Json) - If the document isn't valid JSON
+/// * [`Error::Parse`](crate::Error::Parse) - If nothing exists at `pointer`,
+///   or the value there isn't an array
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::net::json;
+///
+/// let body = br#"{"result":"ok","data":[{"id":"a"},{"id":7},{"id":"c"}]}"#;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Entry {
+///     id: String,
+/// }
+///
+/// let ids: Vec<String> = json::stream_array::<Entry>(body, "/data")
+///     .unwrap()
+///     .filter_map(|entry| entry.ok())
+///     .map(|entry| entry.id)
+///     .collect();
+/// assert_eq!(ids, ["a", "c"]);
+/// ```
+pub fn stream_array<'a, T>(
+    bytes: &'a [u8],
+    pointer: &str,
+) -> crate::Result<impl Iterator<Item = crate::Result<T>> + 'a>
+where
+    T: DeserializeOwned,
+{
+    let not_found = || crate::Error::parse(format!("JSON pointer not found: {}", pointer));
+    if !pointer.is_empty() && !pointer.starts_with('/') {
+        return Err(not_found());
+    }
+
+    let mut current: &RawValue = serde_json::from_slice(bytes)?;
+    for token in pointer.split('/').skip(1) {
+        let token = token.replace("~1", "/").replace("~0", "~");
+        current = match current.get().trim_start().as_bytes().first() {
+            Some(b'{') => {
+                let mut object: HashMap<String, &RawValue> = serde_json::from_str(current.get())?;
+                object.remove(&token).ok_or_else(not_found)?
+            }
+            Some(b'[') => {
+                let index: usize = token.parse().map_err(|_| not_found())?;
+                let array: Vec<&RawValue> = serde_json::from_str(current.get())?;
+                *array.get(index).ok_or_else(not_found)?
+            }
+            _ => return Err(not_found()),
+        };
+    }
+
+    if !current.get().trim_start().starts_with('[') {
+        return Err(crate::Error::parse(format!(
+            "JSON value at {:?} is not an array",
+            pointer
+        )));
+    }
+
+    // Borrowed raw values only record where each element starts and ends
+    let elements: Vec<&'a RawValue> = serde_json::from_str(current.get())?;
+    Ok(elements
+        .into_iter()
+        .map(|element| serde_json::from_str(element.get()).map_err(Into::into)))
+}
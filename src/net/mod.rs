@@ -5,6 +5,7 @@
 //! - **HTTP Client**: A global, configured HTTP client with connection pooling
 //! - **Rate Limiting**: Per-source rate limiting to respect website policies
 //! - **Retry Logic**: Automatic retries with exponential backoff
+//! - **Middleware**: Per-request hooks for dynamic headers such as auth tokens
 //! - **Content Parsing**: HTML and JSON parsing utilities
 //!
 //! # Examples
@@ -23,11 +24,14 @@
 //! # }
 //! ```
 
+use async_trait::async_trait;
 use bytes::Bytes;
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
-use reqwest::{Client, header::HeaderMap};
+use reqwest::{Client, Method, RequestBuilder, header::HeaderMap};
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 
 pub mod html;
@@ -42,16 +46,95 @@ pub mod json;
 /// - Custom User-Agent header
 ///
 /// The client is created lazily on first use and reused across all HTTP operations.
-static CLIENT: Lazy<Client> = Lazy::new(|| {
-    Client::builder()
-        .timeout(Duration::from_secs(30))
+static CLIENT: Lazy<Client> = Lazy::new(|| build_client(None, DEFAULT_TIMEOUT));
+
+/// Default total request timeout of the global client.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Builds a reqwest client with Tosho's standard configuration and the given timeouts.
+fn build_client(connect_timeout: Option<Duration>, timeout: Duration) -> Client {
+    let mut builder = Client::builder()
+        .timeout(timeout)
         .user_agent("Tosho/0.1.0")
         .pool_max_idle_per_host(10)
         .gzip(true)
-        .brotli(true)
-        .build()
-        .expect("Failed to build HTTP client")
-});
+        .brotli(true);
+    if let Some(connect_timeout) = connect_timeout {
+        builder = builder.connect_timeout(connect_timeout);
+    }
+    builder.build().expect("Failed to build HTTP client")
+}
+
+/// Process-wide count of HTTP requests sent by every [`HttpClient`].
+static TOTAL_REQUESTS: AtomicU64 = AtomicU64::new(0);
+
+/// Returns how many HTTP requests Tosho has sent since startup or the last reset.
+///
+/// Every attempt counts, including retries, across all clients and sources.
+/// For per-source numbers use [`Sources::stats()`](crate::Sources::stats).
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::net;
+///
+/// println!("{} requests sent so far", net::total_requests());
+/// ```
+pub fn total_requests() -> u64 {
+    TOTAL_REQUESTS.load(Ordering::Relaxed)
+}
+
+/// Resets the counter behind [`total_requests()`] to zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::net;
+///
+/// net::reset_request_counter();
+/// ```
+pub fn reset_request_counter() {
+    TOTAL_REQUESTS.store(0, Ordering::Relaxed);
+}
+
+/// Builds a URL query string from key/value pairs.
+///
+/// Both keys and values are percent-encoded, so user input containing `&`, `=`,
+/// spaces or non-ASCII characters can't break the query string. Keys may be
+/// repeated to express array parameters such as `contentRating[]`.
+///
+/// # Parameters
+///
+/// * `pairs` - The query parameters, in order
+///
+/// # Returns
+///
+/// The encoded query string, without a leading `?`.
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::net::build_query;
+///
+/// let query = build_query(&[
+///     ("title", "tom & jerry".to_string()),
+///     ("limit", 20.to_string()),
+/// ]);
+/// assert_eq!(query, "title=tom%20%26%20jerry&limit=20");
+/// ```
+pub fn build_query(pairs: &[(&str, String)]) -> String {
+    pairs
+        .iter()
+        .map(|(key, value)| {
+            format!(
+                "{}={}",
+                urlencoding::encode(key),
+                urlencoding::encode(value)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
 
 /// Per-source rate limiter to prevent overwhelming manga websites.
 ///
@@ -66,6 +149,7 @@ static CLIENT: Lazy<Client> = Lazy::new(|| {
 #[derive(Debug)]
 pub struct RateLimiter {
     last_request: Mutex<HashMap<String, Instant>>,
+    source_delays: Mutex<HashMap<String, Duration>>,
     default_delay: Duration,
 }
 
@@ -73,6 +157,7 @@ impl Clone for RateLimiter {
     fn clone(&self) -> Self {
         Self {
             last_request: Mutex::new(HashMap::new()),
+            source_delays: Mutex::new(self.source_delays.lock().clone()),
             default_delay: self.default_delay,
         }
     }
@@ -96,6 +181,7 @@ impl RateLimiter {
     pub fn new(delay_ms: u64) -> Self {
         Self {
             last_request: Mutex::new(HashMap::new()),
+            source_delays: Mutex::new(HashMap::new()),
             default_delay: Duration::from_millis(delay_ms),
         }
     }
@@ -103,7 +189,9 @@ impl RateLimiter {
     /// Waits if necessary before allowing a request for the specified source.
     ///
     /// This method checks the last request time for the source and sleeps if
-    /// insufficient time has passed since the last request.
+    /// insufficient time has passed since the last request. The delay set via
+    /// [`set_delay_for()`](RateLimiter::set_delay_for) is used if there is one,
+    /// otherwise the default delay.
     ///
     /// # Parameters
     ///
@@ -120,28 +208,8 @@ impl RateLimiter {
     /// # }
     /// ```
     pub async fn wait(&self, source_id: &str) {
-        let now = Instant::now();
-        let wait_duration = {
-            let last_map = self.last_request.lock();
-            if let Some(&last) = last_map.get(source_id) {
-                let elapsed = now.duration_since(last);
-                if elapsed < self.default_delay {
-                    Some(self.default_delay - elapsed)
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
-        };
-
-        if let Some(duration) = wait_duration {
-            tokio::time::sleep(duration).await;
-        }
-
-        self.last_request
-            .lock()
-            .insert(source_id.to_string(), Instant::now());
+        let delay = self.delay_for(source_id);
+        self.wait_custom(source_id, delay).await;
     }
 
     /// Waits with a custom delay for a specific source.
@@ -190,6 +258,128 @@ impl RateLimiter {
             .lock()
             .insert(source_id.to_string(), Instant::now());
     }
+
+    /// Sets the delay used by [`wait()`](RateLimiter::wait) for one source.
+    ///
+    /// This lets a single limiter serve sources with different rate limits.
+    /// Sources without an explicit delay use the default delay.
+    ///
+    /// # Parameters
+    ///
+    /// * `source_id` - The identifier of the source
+    /// * `delay` - Minimum delay between requests to this source
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::net::RateLimiter;
+    /// use std::time::Duration;
+    ///
+    /// let limiter = RateLimiter::new(200);
+    /// limiter.set_delay_for("slow_source", Duration::from_secs(2));
+    /// ```
+    pub fn set_delay_for(&self, source_id: &str, delay: Duration) {
+        self.source_delays
+            .lock()
+            .insert(source_id.to_string(), delay);
+    }
+
+    /// Returns the delay [`wait()`](RateLimiter::wait) enforces for one source.
+    ///
+    /// # Parameters
+    ///
+    /// * `source_id` - The identifier of the source
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::net::RateLimiter;
+    /// use std::time::Duration;
+    ///
+    /// let limiter = RateLimiter::new(200);
+    /// limiter.set_delay_for("slow_source", Duration::from_secs(2));
+    /// assert_eq!(limiter.delay_for("slow_source"), Duration::from_secs(2));
+    /// assert_eq!(limiter.delay_for("other"), Duration::from_millis(200));
+    /// ```
+    pub fn delay_for(&self, source_id: &str) -> Duration {
+        self.source_delays
+            .lock()
+            .get(source_id)
+            .copied()
+            .unwrap_or(self.default_delay)
+    }
+
+    /// Forgets all recorded request times, so the next request for every
+    /// source goes through immediately. Per-source delays are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::net::RateLimiter;
+    ///
+    /// # async fn example() {
+    /// let limiter = RateLimiter::new(1000);
+    /// limiter.wait("mangadex").await;
+    /// limiter.reset();
+    /// limiter.wait("mangadex").await; // Doesn't wait
+    /// # }
+    /// ```
+    pub fn reset(&self) {
+        self.last_request.lock().clear();
+    }
+
+    /// Forgets the recorded request time of one source, so its next request
+    /// goes through immediately.
+    ///
+    /// # Parameters
+    ///
+    /// * `source_id` - The identifier of the source to reset
+    pub fn reset_source(&self, source_id: &str) {
+        self.last_request.lock().remove(source_id);
+    }
+}
+
+/// Snapshot of the request counters of an [`HttpClient`].
+///
+/// Obtained via [`HttpClient::stats()`] or aggregated per source with
+/// [`Sources::stats()`](crate::Sources::stats).
+///
+/// # Fields
+///
+/// * `requests` - HTTP requests sent, including retries
+/// * `retries` - Requests repeated after a network error, a 429 or an unparsable JSON body
+/// * `rate_limited` - Responses with status 429 Too Many Requests
+/// * `errors` - Requests that still failed after all retries (HTTP, network or rate limit errors)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HttpStats {
+    pub requests: u64,
+    pub retries: u64,
+    pub rate_limited: u64,
+    pub errors: u64,
+}
+
+/// Atomic counters backing [`HttpStats`].
+#[derive(Debug, Default)]
+struct HttpCounters {
+    requests: AtomicU64,
+    retries: AtomicU64,
+    rate_limited: AtomicU64,
+    errors: AtomicU64,
+}
+
+impl HttpCounters {
+    fn increment(counter: &AtomicU64) {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> HttpStats {
+        HttpStats {
+            requests: self.requests.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+            rate_limited: self.rate_limited.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+        }
+    }
 }
 
 /// HTTP client wrapper with built-in rate limiting and retry logic.
@@ -225,14 +415,74 @@ pub struct HttpClient {
     rate_limiter: RateLimiter,
     max_retries: u32,
     headers: HeaderMap,
+    user_agents: Arc<[String]>,
+    next_user_agent: Arc<AtomicUsize>,
+    middleware: Vec<Arc<dyn RequestMiddleware>>,
+    counters: Arc<HttpCounters>,
+    max_response_size: usize,
+    client: Client,
+    connect_timeout: Option<Duration>,
+    read_timeout: Duration,
+}
+
+/// Adjusts every request an [`HttpClient`] sends, right before it goes out.
+///
+/// Headers set with [`with_header()`](HttpClient::with_header) are fixed when
+/// the client is built. A middleware runs once per attempt instead, including
+/// retries, so it can add values that change over time, such as refreshed
+/// auth tokens, session cookies or CSRF tokens. Register it with
+/// [`HttpClient::with_middleware()`].
+///
+/// # Examples
+///
+/// ```rust
+/// use async_trait::async_trait;
+/// use reqwest::RequestBuilder;
+/// use std::sync::Arc;
+/// use tosho::net::{HttpClient, RequestMiddleware};
+///
+/// struct BearerToken;
+///
+/// #[async_trait]
+/// impl RequestMiddleware for BearerToken {
+///     async fn apply(&self, request: RequestBuilder) -> RequestBuilder {
+///         // e.g. fetch a fresh token from a cache or auth server
+///         request.bearer_auth("token")
+///     }
+/// }
+///
+/// let client = HttpClient::new("source").with_middleware(Arc::new(BearerToken));
+/// ```
+#[async_trait]
+pub trait RequestMiddleware: Send + Sync {
+    /// Returns the request to send, usually with extra headers added.
+    async fn apply(&self, request: RequestBuilder) -> RequestBuilder;
+}
+
+impl std::fmt::Debug for dyn RequestMiddleware {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RequestMiddleware")
+    }
 }
 
+/// Longest `Retry-After` delay, in seconds, honored before retrying a 429 response.
+const MAX_RETRY_AFTER_SECS: u64 = 60;
+
+/// Default cap on the size of a single response body (50 MiB).
+///
+/// Large enough for high-resolution page images, small enough that a
+/// misbehaving server can't exhaust memory. See
+/// [`HttpClient::with_max_response_size()`].
+pub const DEFAULT_MAX_RESPONSE_SIZE: usize = 50 * 1024 * 1024;
+
 impl HttpClient {
     /// Creates a new HTTP client for the specified source.
     ///
     /// The client is initialized with sensible defaults:
     /// - 200ms rate limit delay
     /// - 3 maximum retries
+    /// - [`DEFAULT_MAX_RESPONSE_SIZE`] response body cap
+    /// - 30-second request timeout, shared connection pool
     ///
     /// # Parameters
     ///
@@ -251,6 +501,14 @@ impl HttpClient {
             rate_limiter: RateLimiter::new(200), // 200ms default
             max_retries: 3,
             headers: HeaderMap::new(),
+            user_agents: Arc::from(Vec::new()),
+            next_user_agent: Arc::new(AtomicUsize::new(0)),
+            middleware: Vec::new(),
+            counters: Arc::new(HttpCounters::default()),
+            max_response_size: DEFAULT_MAX_RESPONSE_SIZE,
+            client: CLIENT.clone(),
+            connect_timeout: None,
+            read_timeout: DEFAULT_TIMEOUT,
         }
     }
 
@@ -273,6 +531,45 @@ impl HttpClient {
         self
     }
 
+    /// Returns the current delay between requests in milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::net::HttpClient;
+    ///
+    /// let client = HttpClient::new("source").with_rate_limit(1000);
+    /// assert_eq!(client.rate_limit_ms(), 1000);
+    /// ```
+    pub fn rate_limit_ms(&self) -> u64 {
+        self.rate_limiter.delay_for(&self.source_id).as_millis() as u64
+    }
+
+    /// Changes the delay between requests of an existing client.
+    ///
+    /// Unlike [`with_rate_limit()`](HttpClient::with_rate_limit), this works
+    /// through a shared reference, so the delay of a client owned by a source
+    /// can be tuned after the source was built. Clones made before the change
+    /// keep their old delay.
+    ///
+    /// # Parameters
+    ///
+    /// * `delay_ms` - Minimum delay between requests in milliseconds
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::net::HttpClient;
+    ///
+    /// let client = HttpClient::new("source");
+    /// client.set_rate_limit(2500);
+    /// assert_eq!(client.rate_limit_ms(), 2500);
+    /// ```
+    pub fn set_rate_limit(&self, delay_ms: u64) {
+        self.rate_limiter
+            .set_delay_for(&self.source_id, Duration::from_millis(delay_ms));
+    }
+
     /// Sets the maximum number of retries for failed requests.
     ///
     /// # Parameters
@@ -292,6 +589,81 @@ impl HttpClient {
         self
     }
 
+    /// Sets the maximum size of a response body.
+    ///
+    /// Bodies are read incrementally and the request fails as soon as the limit
+    /// is exceeded, so an oversized response never gets fully buffered.
+    ///
+    /// # Parameters
+    ///
+    /// * `bytes` - Maximum body size in bytes (after decompression)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::net::HttpClient;
+    ///
+    /// let client = HttpClient::new("source")
+    ///     .with_max_response_size(5 * 1024 * 1024); // 5 MiB
+    /// ```
+    pub fn with_max_response_size(mut self, bytes: usize) -> Self {
+        self.max_response_size = bytes;
+        self
+    }
+
+    /// Sets how long to wait for a connection to be established.
+    ///
+    /// A short connect timeout makes requests to dead hosts fail fast, while
+    /// [`with_read_timeout()`](HttpClient::with_read_timeout) can stay generous
+    /// for slow responses. By default only the 30-second total timeout applies.
+    ///
+    /// Changing a timeout gives this client its own connection pool instead of
+    /// the one shared by all clients.
+    ///
+    /// # Parameters
+    ///
+    /// * `timeout` - Maximum time to spend connecting
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tosho::net::HttpClient;
+    ///
+    /// let client = HttpClient::new("source")
+    ///     .with_connect_timeout(Duration::from_secs(3))
+    ///     .with_read_timeout(Duration::from_secs(90));
+    /// ```
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self.client = build_client(self.connect_timeout, self.read_timeout);
+        self
+    }
+
+    /// Sets the total time allowed for a request, from connecting until the
+    /// body has been read (30 seconds by default).
+    ///
+    /// Like [`with_connect_timeout()`](HttpClient::with_connect_timeout), this
+    /// gives the client its own connection pool.
+    ///
+    /// # Parameters
+    ///
+    /// * `timeout` - Maximum duration of a single request attempt
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use tosho::net::HttpClient;
+    ///
+    /// let client = HttpClient::new("slow_source").with_read_timeout(Duration::from_secs(120));
+    /// ```
+    pub fn with_read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = timeout;
+        self.client = build_client(self.connect_timeout, self.read_timeout);
+        self
+    }
+
     /// Adds a custom header to all requests made by this client.
     ///
     /// # Parameters
@@ -318,11 +690,75 @@ impl HttpClient {
         self
     }
 
+    /// Rotates the `User-Agent` header through a pool of values, one per request.
+    ///
+    /// Some sites block the default Tosho user agent outright. With a pool set,
+    /// every request (including retries) uses the next user agent in
+    /// round-robin order, overriding any `User-Agent` set via
+    /// [`with_header()`](HttpClient::with_header). Clones of the client share
+    /// the rotation. An empty pool disables rotation, and values that are not
+    /// valid header values are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `user_agents` - The user agent strings to rotate through
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::net::HttpClient;
+    ///
+    /// let client = HttpClient::new("source").with_user_agents(vec![
+    ///     "Mozilla/5.0 (Windows NT 10.0; Win64; x64) Firefox/128.0".to_string(),
+    ///     "Mozilla/5.0 (Macintosh; Intel Mac OS X 14_5) Safari/605.1.15".to_string(),
+    /// ]);
+    /// ```
+    pub fn with_user_agents(mut self, user_agents: Vec<String>) -> Self {
+        let valid: Vec<String> = user_agents
+            .into_iter()
+            .filter(|ua| ua.parse::<reqwest::header::HeaderValue>().is_ok())
+            .collect();
+        self.user_agents = Arc::from(valid);
+        self
+    }
+
+    /// Registers a middleware that adjusts every request before it is sent.
+    ///
+    /// Middleware runs after the configured headers and user agent are applied.
+    /// Several middleware run in the order they were registered. Clones of the
+    /// client share the registered middleware.
+    ///
+    /// # Parameters
+    ///
+    /// * `middleware` - The middleware to run for every request
+    ///
+    /// # Examples
+    ///
+    /// See [`RequestMiddleware`].
+    pub fn with_middleware(mut self, middleware: Arc<dyn RequestMiddleware>) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Headers for the next request, with the next rotated user agent applied.
+    fn request_headers(&self) -> HeaderMap {
+        let mut headers = self.headers.clone();
+        if !self.user_agents.is_empty() {
+            let index = self.next_user_agent.fetch_add(1, Ordering::Relaxed);
+            let user_agent = &self.user_agents[index % self.user_agents.len()];
+            if let Ok(value) = user_agent.parse() {
+                headers.insert(reqwest::header::USER_AGENT, value);
+            }
+        }
+        headers
+    }
+
     /// Performs a GET request with automatic retry logic and rate limiting.
     ///
     /// This method applies rate limiting, handles HTTP errors, and retries failed
-    /// requests with exponential backoff. It handles 429 (Too Many Requests) responses
-    /// specially by respecting the `Retry-After` header.
+    /// requests with exponential backoff. 429 (Too Many Requests) responses are
+    /// retried after the delay given by their `Retry-After` header (in seconds,
+    /// capped at one minute), or with exponential backoff if there is none.
     ///
     /// # Parameters
     ///
@@ -335,8 +771,10 @@ impl HttpClient {
     /// # Errors
     ///
     /// * [`Error::RateLimit`](crate::Error::RateLimit) - If rate limited after retries
-    /// * [`Error::Source`](crate::Error::Source) - For HTTP errors (4xx, 5xx)
+    /// * [`Error::Source`](crate::Error::Source) - For HTTP errors (4xx, 5xx), with `status` set
     /// * [`Error::Network`](crate::Error::Network) - For network/connection errors
+    /// * [`Error::Parse`](crate::Error::Parse) - If the body exceeds the
+    ///   [maximum response size](HttpClient::with_max_response_size)
     ///
     /// # Examples
     ///
@@ -350,45 +788,130 @@ impl HttpClient {
     /// # }
     /// ```
     pub async fn get(&self, url: &str) -> crate::Result<Bytes> {
+        let result = self.get_with_retries(url).await;
+        if result.is_err() {
+            HttpCounters::increment(&self.counters.errors);
+        }
+        result
+    }
+
+    /// Performs a HEAD request and returns the response headers.
+    ///
+    /// Useful for checking that a resource exists, or reading its
+    /// `Content-Length`, without downloading the body. The request goes through
+    /// the same rate limiting and retry handling as [`get()`](HttpClient::get).
+    ///
+    /// # Parameters
+    ///
+    /// * `url` - The URL to request
+    ///
+    /// # Returns
+    ///
+    /// The headers of the successful response.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::RateLimit`](crate::Error::RateLimit) - If rate limited after retries
+    /// * [`Error::Source`](crate::Error::Source) - For HTTP errors (4xx, 5xx), with `status` set
+    /// * [`Error::Network`](crate::Error::Network) - For network/connection errors
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::net::HttpClient;
+    ///
+    /// # async fn example() -> tosho::Result<()> {
+    /// let client = HttpClient::new("source");
+    /// let headers = client.head("https://example.com/covers/123.jpg").await?;
+    /// let size = headers
+    ///     .get(reqwest::header::CONTENT_LENGTH)
+    ///     .and_then(|v| v.to_str().ok())
+    ///     .and_then(|v| v.parse::<u64>().ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn head(&self, url: &str) -> crate::Result<HeaderMap> {
+        let result = self
+            .send_with_retries(Method::HEAD, url)
+            .await
+            .map(|response| response.headers().clone());
+        if result.is_err() {
+            HttpCounters::increment(&self.counters.errors);
+        }
+        result
+    }
+
+    /// Retry loop behind [`get()`](HttpClient::get).
+    async fn get_with_retries(&self, url: &str) -> crate::Result<Bytes> {
+        let response = self.send_with_retries(Method::GET, url).await?;
+        self.read_body(response).await
+    }
+
+    /// Sends a request until it succeeds, retrying rate limits and network errors.
+    async fn send_with_retries(
+        &self,
+        method: Method,
+        url: &str,
+    ) -> crate::Result<reqwest::Response> {
         let mut attempts = 0;
 
         loop {
             // Apply rate limiting
             self.rate_limiter.wait(&self.source_id).await;
 
-            match CLIENT.get(url).headers(self.headers.clone()).send().await {
+            let mut request = self
+                .client
+                .request(method.clone(), url)
+                .headers(self.request_headers());
+            for middleware in &self.middleware {
+                request = middleware.apply(request).await;
+            }
+
+            HttpCounters::increment(&self.counters.requests);
+            HttpCounters::increment(&TOTAL_REQUESTS);
+            match request.send().await {
                 Ok(response) => {
                     if response.status().is_success() {
-                        return Ok(response.bytes().await?);
+                        return Ok(response);
                     }
 
                     // Handle rate limiting
                     if response.status() == 429 {
+                        HttpCounters::increment(&self.counters.rate_limited);
+                        let retry_after = response
+                            .headers()
+                            .get("retry-after")
+                            .and_then(|v| v.to_str().ok())
+                            .and_then(|v| v.trim().parse::<u64>().ok());
+
                         if attempts < self.max_retries {
                             attempts += 1;
-                            let delay = Duration::from_secs(2_u64.pow(attempts));
+                            HttpCounters::increment(&self.counters.retries);
+                            // Wait as long as the server asks, within reason,
+                            // and fall back to exponential backoff otherwise
+                            let delay = match retry_after {
+                                Some(seconds) => {
+                                    Duration::from_secs(seconds.min(MAX_RETRY_AFTER_SECS))
+                                }
+                                None => Duration::from_secs(2_u64.pow(attempts)),
+                            };
                             tokio::time::sleep(delay).await;
                             continue;
                         }
 
-                        let retry_after = response
-                            .headers()
-                            .get("retry-after")
-                            .and_then(|v| v.to_str().ok())
-                            .and_then(|v| v.parse::<u64>().ok());
-
                         return Err(crate::Error::rate_limit(retry_after));
                     }
 
                     // Other HTTP errors
-                    return Err(crate::Error::source(
+                    return Err(crate::Error::source_status(
                         &self.source_id,
-                        format!("HTTP {}", response.status()),
+                        response.status().as_u16(),
                     ));
                 }
                 Err(e) => {
                     if attempts < self.max_retries {
                         attempts += 1;
+                        HttpCounters::increment(&self.counters.retries);
                         tokio::time::sleep(Duration::from_secs(1)).await;
                         continue;
                     }
@@ -398,6 +921,21 @@ impl HttpClient {
         }
     }
 
+    /// Reads a response body chunk by chunk, enforcing the maximum response size.
+    async fn read_body(&self, mut response: reqwest::Response) -> crate::Result<Bytes> {
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            if body.len() + chunk.len() > self.max_response_size {
+                return Err(crate::Error::parse(format!(
+                    "Response too large (over {} bytes)",
+                    self.max_response_size
+                )));
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(Bytes::from(body))
+    }
+
     /// Performs a GET request and returns the response as a UTF-8 string.
     ///
     /// This is a convenience method that calls [`get()`](HttpClient::get) and converts
@@ -433,6 +971,78 @@ impl HttpClient {
             .map_err(|e| crate::Error::parse(format!("Invalid UTF-8: {}", e)))
     }
 
+    /// Performs a GET request and returns the response as a string, replacing
+    /// invalid UTF-8 sequences with `U+FFFD`.
+    ///
+    /// Unlike [`get_text()`](HttpClient::get_text), this never fails on
+    /// malformed bytes, which makes it usable for pages that are mostly UTF-8
+    /// but contain a few stray bytes.
+    ///
+    /// # Parameters
+    ///
+    /// * `url` - The URL to request
+    ///
+    /// # Errors
+    ///
+    /// * All errors from [`get()`](HttpClient::get)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::net::HttpClient;
+    ///
+    /// # async fn example() -> tosho::Result<()> {
+    /// let client = HttpClient::new("source");
+    /// let html = client.get_text_lossy("https://example.com/manga/123").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_text_lossy(&self, url: &str) -> crate::Result<String> {
+        let bytes = self.get(url).await?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    /// Performs a GET request and decodes the response using the given charset.
+    ///
+    /// Use this for legacy sites that serve pages in encodings such as
+    /// Shift-JIS or Latin-1. The charset is given by its WHATWG label (e.g.
+    /// `"shift_jis"`, `"euc-jp"`, `"latin1"`), the same names used in
+    /// `<meta charset>` and `Content-Type` headers. A byte order mark in the
+    /// response overrides the given charset, and malformed sequences are
+    /// replaced with `U+FFFD`.
+    ///
+    /// # Parameters
+    ///
+    /// * `url` - The URL to request
+    /// * `encoding` - The charset label to decode with
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::Parse`](crate::Error::Parse) - If the charset label is unknown
+    /// * All errors from [`get()`](HttpClient::get)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::net::HttpClient;
+    ///
+    /// # async fn example() -> tosho::Result<()> {
+    /// let client = HttpClient::new("source");
+    /// let html = client
+    ///     .get_text_encoded("https://example.jp/manga/123", "shift_jis")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_text_encoded(&self, url: &str, encoding: &str) -> crate::Result<String> {
+        let encoding = encoding_rs::Encoding::for_label(encoding.trim().as_bytes())
+            .ok_or_else(|| crate::Error::parse(format!("Unknown encoding: {}", encoding)))?;
+
+        let bytes = self.get(url).await?;
+        let (text, _, _) = encoding.decode(&bytes);
+        Ok(text.into_owned())
+    }
+
     /// Performs a GET request and deserializes the response as JSON.
     ///
     /// This is a convenience method that calls [`get()`](HttpClient::get) and
@@ -480,4 +1090,81 @@ impl HttpClient {
         let bytes = self.get(url).await?;
         serde_json::from_slice(&bytes).map_err(Into::into)
     }
+
+    /// Performs a GET request and deserializes the response as JSON, retrying
+    /// when the body fails to parse.
+    ///
+    /// Some APIs occasionally return a truncated or otherwise invalid JSON body
+    /// under load, even though a repeated request succeeds. This method treats
+    /// such parse failures as retryable: the URL is re-fetched and re-parsed up to
+    /// [`with_max_retries()`](HttpClient::with_max_retries) times before the parse
+    /// error is returned. Transport errors are still retried by
+    /// [`get()`](HttpClient::get) as usual.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type to deserialize the JSON into
+    ///
+    /// # Parameters
+    ///
+    /// * `url` - The URL to request
+    ///
+    /// # Errors
+    ///
+    /// * All errors from [`get()`](HttpClient::get)
+    /// * [`Error::Json`](crate::Error::Json) - If every attempt returned invalid JSON
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::net::HttpClient;
+    ///
+    /// # async fn example() -> tosho::Result<()> {
+    /// let client = HttpClient::new("source").with_max_retries(2);
+    /// let data: serde_json::Value = client
+    ///     .get_json_with_retry_on_parse("https://api.example.com/manga/123")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_json_with_retry_on_parse<T>(&self, url: &str) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let mut attempts = 0;
+
+        loop {
+            let bytes = self.get(url).await?;
+
+            match serde_json::from_slice(&bytes) {
+                Ok(value) => return Ok(value),
+                Err(_) if attempts < self.max_retries => {
+                    attempts += 1;
+                    HttpCounters::increment(&self.counters.retries);
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Returns a snapshot of the request counters of this client.
+    ///
+    /// Counters are shared between clones of the same client, so a source's
+    /// clones all report into the same totals. Updates use relaxed atomics, so
+    /// a snapshot taken while requests are in flight may be slightly stale.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::net::HttpClient;
+    ///
+    /// let client = HttpClient::new("source");
+    /// let stats = client.stats();
+    /// assert_eq!(stats.requests, 0);
+    /// assert_eq!(stats.errors, 0);
+    /// ```
+    pub fn stats(&self) -> HttpStats {
+        self.counters.snapshot()
+    }
 }
@@ -33,10 +33,15 @@
 //! # }
 //! ```
 
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use futures::{Stream, StreamExt, stream::FuturesUnordered};
+
 use crate::{
-    error::Result,
-    source::Sources,
-    types::{Manga, SearchParams, SortOrder},
+    error::{Error, Result},
+    source::{Capability, Sources, search_source},
+    types::{Manga, SearchParams, SortOrder, normalize_title},
 };
 
 /// A fluent search builder that can build search parameters and execute searches.
@@ -48,10 +53,16 @@ use crate::{
 /// # Execution Strategies
 ///
 /// - [`flatten()`](SearchBuilder::flatten) - Returns all results in a single vector
+/// - [`flatten_paged()`](SearchBuilder::flatten_paged) - Returns one page of the merged results
 /// - [`group()`](SearchBuilder::group) - Returns results grouped by source
+/// - [`execute()`](SearchBuilder::execute) - Returns a [`SearchResult`] with the query context
 /// - [`from_source()`](SearchBuilder::from_source) - Searches only a specific source
+/// - [`from_sources_fallback()`](SearchBuilder::from_sources_fallback) - Tries sources in order until one has results
 /// - [`build()`](SearchBuilder::build) - Returns just the search parameters
 ///
+/// Builders are `Clone`, so a shared base query can be forked into variants,
+/// e.g. one sorted by title and one by update date.
+///
 /// # Examples
 ///
 /// ```rust
@@ -80,9 +91,80 @@ use crate::{
 /// # Ok(())
 /// # }
 /// ```
+#[derive(Clone)]
 pub struct SearchBuilder<'a> {
     sources: &'a Sources,
     params: SearchParams,
+    dedupe: DedupeMode,
+    total_limit: Option<usize>,
+    required: Vec<Capability>,
+    deadline: Option<Duration>,
+}
+
+/// How [`SearchBuilder::flatten()`] removes duplicate results.
+///
+/// # Variants
+///
+/// * `Off` - Keep every result (default)
+/// * `ByTitle` - Keep the first result per [`normalize_title`] form, like
+///   [`SearchResultExt::dedupe_by_title()`]
+/// * `BySourceId` - Keep the first result per source and manga ID, dropping
+///   entries a source returned more than once
+/// * `Fuzzy(threshold)` - Drop results whose normalized title is at least
+///   `threshold` similar (0.0-1.0) to an earlier result's
+///
+/// # Examples
+///
+/// ```rust
+/// # use tosho::prelude::*;
+/// # use tosho::error::Result;
+/// # async fn example() -> Result<()> {
+/// # let sources = Sources::new();
+///
+/// let unique = sources
+///     .search("one piece")
+///     .dedupe(DedupeMode::Fuzzy(0.9))
+///     .flatten()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DedupeMode {
+    #[default]
+    Off,
+    ByTitle,
+    BySourceId,
+    Fuzzy(f64),
+}
+
+impl DedupeMode {
+    /// Removes duplicates from `results` according to this mode, keeping the first occurrence
+    fn apply(self, mut results: Vec<Manga>) -> Vec<Manga> {
+        match self {
+            DedupeMode::Off => results,
+            DedupeMode::ByTitle => results.dedupe_by_title(),
+            DedupeMode::BySourceId => {
+                let mut seen = std::collections::HashSet::new();
+                results.retain(|manga| seen.insert((manga.source_id.clone(), manga.id.clone())));
+                results
+            }
+            DedupeMode::Fuzzy(threshold) => {
+                let mut kept_titles: Vec<Vec<char>> = Vec::new();
+                results.retain(|manga| {
+                    let title: Vec<char> = normalize_title(&manga.title).chars().collect();
+                    let duplicate = kept_titles
+                        .iter()
+                        .any(|kept| title_similarity(kept, &title) >= threshold);
+                    if !duplicate {
+                        kept_titles.push(title);
+                    }
+                    !duplicate
+                });
+                results
+            }
+        }
+    }
 }
 
 impl<'a> SearchBuilder<'a> {
@@ -97,10 +179,35 @@ impl<'a> SearchBuilder<'a> {
                 query: query.into(),
                 ..Default::default()
             },
+            dedupe: DedupeMode::Off,
+            total_limit: None,
+            required: Vec::new(),
+            deadline: None,
+        }
+    }
+
+    /// Creates a new search builder seeded with existing search parameters.
+    ///
+    /// This method is called internally by [`Sources::search_with()`](crate::source::Sources::search_with).
+    /// You typically don't need to call this directly.
+    pub(crate) fn with_params(sources: &'a Sources, params: SearchParams) -> Self {
+        Self {
+            sources,
+            params,
+            dedupe: DedupeMode::Off,
+            total_limit: None,
+            required: Vec::new(),
+            deadline: None,
         }
     }
 
-    /// Sets the maximum number of results to return.
+    /// Sets the maximum number of results to request from each source.
+    ///
+    /// The limit is sent to every source separately, so [`flatten()`](SearchBuilder::flatten)
+    /// can return up to `limit` results *per source*. Sources that return more
+    /// than asked for, e.g. because they only fetch whole pages, are cut down to
+    /// `limit`. Use [`total_limit()`](SearchBuilder::total_limit) to cap the
+    /// merged list.
     ///
     /// # Examples
     ///
@@ -112,7 +219,7 @@ impl<'a> SearchBuilder<'a> {
     ///
     /// let results = sources
     ///     .search("popular manga")
-    ///     .limit(10)  // Only return the first 10 results
+    ///     .limit(10)  // At most 10 results from each source
     ///     .flatten()
     ///     .await?;
     /// # Ok(())
@@ -123,10 +230,79 @@ impl<'a> SearchBuilder<'a> {
         self
     }
 
+    /// Sets the maximum number of results in the merged list.
+    ///
+    /// Unlike [`limit()`](SearchBuilder::limit), which is passed to each source,
+    /// this truncates the combined results of [`flatten()`](SearchBuilder::flatten)
+    /// and [`execute()`](SearchBuilder::execute) after deduplication. The two can be
+    /// combined: `limit` bounds how much each source fetches, `total_limit` bounds
+    /// what the caller gets back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # use tosho::error::Result;
+    /// # async fn example() -> Result<()> {
+    /// # let sources = Sources::new();
+    ///
+    /// let results = sources
+    ///     .search("popular manga")
+    ///     .total_limit(20)  // At most 20 results overall
+    ///     .flatten()
+    ///     .await?;
+    /// assert!(results.len() <= 20);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn total_limit(mut self, total_limit: usize) -> Self {
+        self.total_limit = Some(total_limit);
+        self
+    }
+
+    /// Bounds how long the whole search may take.
+    ///
+    /// Once `deadline` has passed, [`flatten()`](SearchBuilder::flatten),
+    /// [`group()`](SearchBuilder::group) and the methods built on them stop
+    /// waiting, cancel the sources that haven't answered yet and return what
+    /// the others produced. Sources that miss the deadline are left out of the
+    /// results rather than reported as failures, so a search that times out
+    /// entirely comes back empty instead of failing. With a deadline,
+    /// [`group()`](SearchBuilder::group) lists sources in the order they
+    /// answered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # use tosho::error::Result;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<()> {
+    /// # let sources = Sources::new();
+    /// let results = sources
+    ///     .search("one piece")
+    ///     .deadline(Duration::from_secs(5))  // Whatever arrived within 5 seconds
+    ///     .flatten()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
     /// Sets the offset for pagination.
     ///
     /// Use this in combination with [`limit()`](SearchBuilder::limit) to implement pagination.
     ///
+    /// The offset and limit are passed to every source as-is, so they page
+    /// through *each source's* results independently: with two sources,
+    /// `offset(10)` skips the first 10 results of both, not the first 10 of the
+    /// merged list. Use [`flatten_paged()`](SearchBuilder::flatten_paged) to
+    /// page through the combined results instead.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -135,7 +311,7 @@ impl<'a> SearchBuilder<'a> {
     /// # async fn example() -> Result<()> {
     /// # let sources = Sources::new();
     ///
-    /// // Get the second page of results (items 10-19)
+    /// // Get the second page of each source's results (items 10-19)
     /// let page_2 = sources
     ///     .search("manga")
     ///     .limit(10)
@@ -219,6 +395,136 @@ impl<'a> SearchBuilder<'a> {
         self
     }
 
+    /// Sets how [`flatten()`](SearchBuilder::flatten) removes duplicate results.
+    ///
+    /// Defaults to [`DedupeMode::Off`]. Has no effect on
+    /// [`group()`](SearchBuilder::group) or [`from_source()`](SearchBuilder::from_source).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # use tosho::error::Result;
+    /// # async fn example() -> Result<()> {
+    /// # let sources = Sources::new();
+    ///
+    /// let unique = sources
+    ///     .search("one piece")
+    ///     .dedupe(DedupeMode::ByTitle)
+    ///     .flatten()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dedupe(mut self, mode: DedupeMode) -> Self {
+        self.dedupe = mode;
+        self
+    }
+
+    /// Only queries sources that support the given capability.
+    ///
+    /// Sources may silently ignore parameters they can't express, e.g. return
+    /// untagged results for a search with [`include_tags()`](SearchBuilder::include_tags).
+    /// Requiring the matching capability leaves such sources out of
+    /// [`flatten()`](SearchBuilder::flatten), [`group()`](SearchBuilder::group) and the
+    /// strategies built on them. Can be called repeatedly to require several
+    /// capabilities; sources are checked via [`Source::capabilities()`](crate::Source::capabilities).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # use tosho::error::Result;
+    /// # async fn example() -> Result<()> {
+    /// # let sources = Sources::new();
+    ///
+    /// let tagged = sources
+    ///     .search("")
+    ///     .include_tags(vec!["Romance".to_string()])
+    ///     .require_capability(Capability::TagFiltering)
+    ///     .flatten()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn require_capability(mut self, capability: Capability) -> Self {
+        if !self.required.contains(&capability) {
+            self.required.push(capability);
+        }
+        self
+    }
+
+    /// Returns the search parameters accumulated so far.
+    ///
+    /// Unlike [`build()`](SearchBuilder::build), this doesn't consume the builder,
+    /// so the parameters can be inspected (e.g. logged) before the search runs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # let sources = Sources::new();
+    /// let builder = sources.search("one piece").limit(20);
+    /// assert_eq!(builder.params().query, "one piece");
+    /// assert_eq!(builder.params().limit, Some(20));
+    /// ```
+    pub fn params(&self) -> &SearchParams {
+        &self.params
+    }
+
+    /// Returns a mutable reference to the search parameters accumulated so far.
+    ///
+    /// This allows wrappers to adjust a builder they received without consuming
+    /// it, for example to clamp the limit or append default tags.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # let sources = Sources::new();
+    /// let mut builder = sources.search("one piece").limit(500);
+    ///
+    /// // Clamp overly large limits
+    /// if builder.params().limit > Some(100) {
+    ///     builder.params_mut().limit = Some(100);
+    /// }
+    ///
+    /// assert_eq!(builder.build().limit, Some(100));
+    /// ```
+    pub fn params_mut(&mut self) -> &mut SearchParams {
+        &mut self.params
+    }
+
+    /// Describes the requests this search would make, without sending them.
+    ///
+    /// Collects [`Source::explain()`](crate::Source::explain) from every source
+    /// the search would query, honoring
+    /// [`require_capability()`](SearchBuilder::require_capability). Handy for
+    /// bug reports about a source returning nothing.
+    ///
+    /// # Returns
+    ///
+    /// One entry per source, pairing its ID with the `(label, url)` pairs it
+    /// reported. Sources that can't describe their requests report an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # let sources = Sources::new();
+    /// for (source_id, requests) in sources.search("one piece").explain() {
+    ///     for (label, url) in requests {
+    ///         println!("{source_id} {label}: {url}");
+    ///     }
+    /// }
+    /// ```
+    pub fn explain(&self) -> Vec<(String, Vec<(String, String)>)> {
+        self.sources
+            .capable_sources(&self.required)
+            .map(|source| (source.id().to_string(), source.explain(&self.params)))
+            .collect()
+    }
+
     /// Executes the search across all sources and returns flattened results.
     ///
     /// This method searches all available sources concurrently and combines the results
@@ -234,6 +540,9 @@ impl<'a> SearchBuilder<'a> {
     /// Returns an error only if all sources fail. Individual source failures are
     /// ignored as long as at least one source returns results.
     ///
+    /// Duplicates are removed according to [`dedupe()`](SearchBuilder::dedupe),
+    /// then the list is truncated to [`total_limit()`](SearchBuilder::total_limit).
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -253,7 +562,117 @@ impl<'a> SearchBuilder<'a> {
     /// # }
     /// ```
     pub async fn flatten(self) -> Result<Vec<Manga>> {
-        self.sources.search_all_flat(self.params).await
+        let dedupe = self.dedupe;
+        let total_limit = self.total_limit;
+        let grouped = self.run_grouped().await;
+        let results = Sources::merge_grouped(grouped)?;
+        let mut results = dedupe.apply(results);
+        if let Some(total_limit) = total_limit {
+            results.truncate(total_limit);
+        }
+        Ok(results)
+    }
+
+    /// Browses the catalogs of all sources by filters alone, without a title query.
+    ///
+    /// Sources that support it treat an empty query as a browse listing, so
+    /// "all completed Action manga by popularity" needs no title. Otherwise this
+    /// behaves like [`flatten()`](SearchBuilder::flatten), but it refuses to run
+    /// without a filter, since an unfiltered browse would page through every
+    /// source's entire catalog. The text query, if any, is left as it is.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::Other`] - If no tags or sort order were set
+    /// * Otherwise the same as [`flatten()`](SearchBuilder::flatten)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # use tosho::error::Result;
+    /// # async fn example() -> Result<()> {
+    /// # let sources = Sources::new();
+    ///
+    /// let popular_action = sources
+    ///     .search("")
+    ///     .include_tags(vec!["Action".to_string()])
+    ///     .sort_by(SortOrder::Popularity)
+    ///     .require_capability(Capability::TagFiltering)
+    ///     .browse()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn browse(self) -> Result<Vec<Manga>> {
+        let params = &self.params;
+        if params.include_tags.is_empty()
+            && params.exclude_tags.is_empty()
+            && params.sort_by.is_none()
+        {
+            return Err(Error::Other(
+                "Browsing requires at least one filter (tags or sort order)".to_string(),
+            ));
+        }
+
+        self.flatten().await
+    }
+
+    /// Executes the search across all sources and returns one page of the merged results.
+    ///
+    /// Unlike combining [`offset()`](SearchBuilder::offset) with
+    /// [`flatten()`](SearchBuilder::flatten), pages are taken from the *combined*
+    /// result list. Every source is queried from offset 0 with a limit of
+    /// `page * per_page`, enough to fill the requested window on its own; the
+    /// results are then merged, deduplicated according to
+    /// [`dedupe()`](SearchBuilder::dedupe), and put in round-robin order with
+    /// [`interleave_by_source()`](SearchResultExt::interleave_by_source), so
+    /// every page mixes all sources instead of exhausting the first one before
+    /// the next appears. Any offset or per-source limit set on the builder is
+    /// ignored; a [`total_limit()`](SearchBuilder::total_limit) still caps the
+    /// interleaved list before it is paged.
+    ///
+    /// Later pages fetch more data from every source, so this is best suited to
+    /// the first few pages of a result set.
+    ///
+    /// # Parameters
+    ///
+    /// * `page` - The 1-based page number (0 is treated as 1)
+    /// * `per_page` - Number of results per page
+    ///
+    /// # Errors
+    ///
+    /// Same as [`flatten()`](SearchBuilder::flatten).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # use tosho::error::Result;
+    /// # async fn example() -> Result<()> {
+    /// # let sources = Sources::new();
+    ///
+    /// // Results 21-40 of the merged, deduplicated list
+    /// let page_2 = sources
+    ///     .search("one piece")
+    ///     .dedupe(DedupeMode::ByTitle)
+    ///     .flatten_paged(2, 20)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn flatten_paged(mut self, page: usize, per_page: usize) -> Result<Vec<Manga>> {
+        let page = page.max(1);
+        self.params.offset = None;
+        self.params.limit = Some(page.saturating_mul(per_page));
+
+        // Cap the interleaved list, not the source-by-source one
+        let total_limit = self.total_limit.take();
+        let mut results = self.flatten().await?.interleave_by_source();
+        if let Some(total_limit) = total_limit {
+            results.truncate(total_limit);
+        }
+        Ok(results.paginate(page, per_page))
     }
 
     /// Executes the search and returns results grouped by source.
@@ -290,7 +709,205 @@ impl<'a> SearchBuilder<'a> {
     /// # }
     /// ```
     pub async fn group(self) -> Vec<(String, Result<Vec<Manga>>)> {
-        self.sources.search_all_grouped(self.params).await
+        self.run_grouped().await
+    }
+
+    /// Queries the capable sources, giving up on those still pending at the deadline.
+    async fn run_grouped(self) -> Vec<(String, Result<Vec<Manga>>)> {
+        let Some(deadline) = self.deadline else {
+            return self
+                .sources
+                .search_capable_grouped(&self.params, &self.required)
+                .await;
+        };
+
+        let deadline = tokio::time::Instant::now() + deadline;
+        let mut results = self.stream();
+        let mut grouped = Vec::new();
+        while let Ok(Some(result)) = tokio::time::timeout_at(deadline, results.next()).await {
+            grouped.push(result);
+        }
+        // Dropping the stream cancels the sources that missed the deadline
+        grouped
+    }
+
+    /// Executes the search and hands each manga to `on_manga` as soon as its source responds.
+    ///
+    /// Unlike [`flatten()`](SearchBuilder::flatten), the combined result list is
+    /// never materialized, which suits large browse operations feeding a channel
+    /// or a database writer. Sources are queried concurrently and their results
+    /// are delivered in the order the sources complete, each source's results in
+    /// its own order. Failing sources are skipped; use
+    /// [`for_each_with_errors()`](SearchBuilder::for_each_with_errors) to observe them.
+    ///
+    /// A [`total_limit()`](SearchBuilder::total_limit) stops delivery once that many
+    /// manga were passed on. [`dedupe()`](SearchBuilder::dedupe) is not applied,
+    /// since it needs the complete list.
+    ///
+    /// # Parameters
+    ///
+    /// * `on_manga` - Called once per manga
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # async fn example() {
+    /// # let sources = Sources::new();
+    /// let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    ///
+    /// sources
+    ///     .search("")
+    ///     .limit(100)
+    ///     .for_each(|manga| {
+    ///         let _ = tx.send(manga);
+    ///     })
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn for_each<F>(self, on_manga: F)
+    where
+        F: FnMut(Manga),
+    {
+        self.for_each_with_errors(on_manga, |_, _| {}).await
+    }
+
+    /// Like [`for_each()`](SearchBuilder::for_each), but also reports failing sources.
+    ///
+    /// # Parameters
+    ///
+    /// * `on_manga` - Called once per manga
+    /// * `on_error` - Called with the source ID and error of every source that fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # async fn example() {
+    /// # let sources = Sources::new();
+    /// let mut count = 0;
+    /// sources
+    ///     .search("one piece")
+    ///     .for_each_with_errors(
+    ///         |_manga| count += 1,
+    ///         |source_id, error| eprintln!("{} failed: {}", source_id, error),
+    ///     )
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn for_each_with_errors<F, E>(self, mut on_manga: F, mut on_error: E)
+    where
+        F: FnMut(Manga),
+        E: FnMut(&str, Error),
+    {
+        let mut remaining = self.total_limit.unwrap_or(usize::MAX);
+        let mut results = self.stream();
+
+        while remaining > 0
+            && let Some((source_id, result)) = results.next().await
+        {
+            match result {
+                Ok(manga) => {
+                    for m in manga.into_iter().take(remaining) {
+                        remaining -= 1;
+                        on_manga(m);
+                    }
+                }
+                Err(error) => on_error(&source_id, error),
+            }
+        }
+    }
+
+    /// Executes the search and yields each source's results as soon as it responds.
+    ///
+    /// Items arrive in the order the sources complete, in the same shape as
+    /// [`group()`](SearchBuilder::group). [`dedupe()`](SearchBuilder::dedupe) and
+    /// [`total_limit()`](SearchBuilder::total_limit) are not applied.
+    ///
+    /// # Cancellation
+    ///
+    /// The stream is cancel-safe. The per-source searches run inside the stream
+    /// itself rather than on spawned tasks, so dropping it (for example when a
+    /// `tokio::select!` branch loses, or the user typed a new query) cancels every
+    /// request still in flight. Results already yielded are unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// use futures::StreamExt;
+    ///
+    /// # async fn example() {
+    /// # let sources = Sources::new();
+    /// let mut results = sources.search("one piece").stream();
+    ///
+    /// // Show whatever arrives first, abandoning the slower sources
+    /// if let Some((source_id, Ok(manga))) = results.next().await {
+    ///     println!("{} answered first with {} results", source_id, manga.len());
+    /// }
+    /// drop(results);
+    /// # }
+    /// ```
+    pub fn stream(self) -> impl Stream<Item = (String, Result<Vec<Manga>>)> + Unpin + 'a {
+        self.sources
+            .capable_sources(&self.required)
+            .map(|source| {
+                let params = self.params.clone();
+                async move { (source.id().to_string(), search_source(source, params).await) }
+            })
+            .collect::<FuturesUnordered<_>>()
+    }
+
+    /// Executes the search and returns the results together with their query context.
+    ///
+    /// This combines what [`flatten()`](SearchBuilder::flatten) and
+    /// [`group()`](SearchBuilder::group) provide: the merged results, plus the
+    /// query, the sources that were consulted and the errors of those that failed.
+    /// Duplicates are removed according to [`dedupe()`](SearchBuilder::dedupe),
+    /// then the list is truncated to [`total_limit()`](SearchBuilder::total_limit).
+    ///
+    /// Never fails as a whole; source failures are recorded in
+    /// [`SearchResult::errors`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # async fn example() {
+    /// # let sources = Sources::new();
+    ///
+    /// let result = sources.search("one piece").execute().await;
+    /// for (source_id, error) in &result.errors {
+    ///     println!("{} failed: {}", source_id, error);
+    /// }
+    ///
+    /// let best_first = result.sorted();
+    /// # }
+    /// ```
+    pub async fn execute(self) -> SearchResult {
+        let query = self.params.query.clone();
+        let dedupe = self.dedupe;
+        let total_limit = self.total_limit;
+
+        let mut result = SearchResult {
+            manga: Vec::new(),
+            query,
+            sources_queried: Vec::new(),
+            errors: Vec::new(),
+        };
+        for (source_id, outcome) in self.group().await {
+            match outcome {
+                Ok(mut manga) => result.manga.append(&mut manga),
+                Err(e) => result.errors.push((source_id.clone(), e.to_string())),
+            }
+            result.sources_queried.push(source_id);
+        }
+
+        let mut result = result.dedupe(dedupe);
+        if let Some(total_limit) = total_limit {
+            result.manga.truncate(total_limit);
+        }
+        result
     }
 
     /// Executes the search on a specific source only.
@@ -329,17 +946,111 @@ impl<'a> SearchBuilder<'a> {
     /// # }
     /// ```
     pub async fn from_source(self, source_id: &str) -> Result<Vec<Manga>> {
-        match self.sources.get(source_id) {
-            Some(source) => {
-                let mut results = source.search(self.params).await?;
-                // Ensure source_id is set
-                for manga in &mut results {
-                    manga.source_id = source_id.to_string();
+        let source = self.sources.get_or_err(source_id)?;
+        search_source(source, self.params).await
+    }
+
+    /// Executes the search on each source in turn until one returns results.
+    ///
+    /// Sources are tried one after another in the given order, not concurrently:
+    /// the first successful, non-empty result is returned and the remaining
+    /// sources are never queried. A source that errors or finds nothing falls
+    /// through to the next one.
+    ///
+    /// The builder's other settings apply as they do for
+    /// [`flatten()`](SearchBuilder::flatten): sources that are disabled or lack a
+    /// [`require_capability()`](SearchBuilder::require_capability) capability
+    /// are skipped, [`deadline()`](SearchBuilder::deadline) bounds the whole
+    /// chain (a source still pending when it passes is left out and no further
+    /// sources are tried), and the winning result goes through
+    /// [`dedupe()`](SearchBuilder::dedupe) and
+    /// [`total_limit()`](SearchBuilder::total_limit).
+    ///
+    /// # Parameters
+    ///
+    /// * `source_ids` - The IDs of the sources to try, in order of preference
+    ///
+    /// # Returns
+    ///
+    /// The first non-empty result, or an empty vector if every source that
+    /// answered found nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if every source fails (unknown source IDs count as
+    /// failures), listing each source's error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # use tosho::error::Result;
+    /// # async fn example() -> Result<()> {
+    /// # let sources = Sources::new();
+    ///
+    /// // Prefer MangaDex, fall back to KissManga
+    /// let results = sources
+    ///     .search("dragon ball")
+    ///     .from_sources_fallback(&["mgd", "kmg"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn from_sources_fallback(self, source_ids: &[&str]) -> Result<Vec<Manga>> {
+        let deadline = self
+            .deadline
+            .map(|deadline| tokio::time::Instant::now() + deadline);
+        let mut errors = Vec::new();
+        let mut any_succeeded = false;
+
+        for &source_id in source_ids {
+            let id = match self.sources.get_or_err(source_id) {
+                Ok(source) => source.id(),
+                Err(e) => {
+                    errors.push(format!("{}: {}", source_id, e));
+                    continue;
+                }
+            };
+            // Disabled sources and those missing a required capability are skipped
+            let Some(source) = self
+                .sources
+                .capable_sources(&self.required)
+                .find(|source| source.id() == id)
+            else {
+                continue;
+            };
+
+            let attempt = search_source(source, self.params.clone());
+            let result = match deadline {
+                Some(deadline) => match tokio::time::timeout_at(deadline, attempt).await {
+                    Ok(result) => result,
+                    // Out of time: the pending source is left out, like in flatten()
+                    Err(_) => break,
+                },
+                None => attempt.await,
+            };
+
+            match result {
+                Ok(results) if !results.is_empty() => {
+                    let mut results = self.dedupe.apply(results);
+                    if let Some(total_limit) = self.total_limit {
+                        results.truncate(total_limit);
+                    }
+                    return Ok(results);
                 }
-                Ok(results)
+                Ok(_) => any_succeeded = true,
+                Err(e) => errors.push(format!("{}: {}", source_id, e)),
             }
-            None => Err(crate::Error::not_found(format!("Source: {}", source_id))),
         }
+
+        if any_succeeded || errors.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Err(crate::Error::Other(format!(
+            "All sources failed: {}",
+            errors.join(", ")
+        )))
     }
 
     /// Builds and returns just the search parameters without executing the search.
@@ -371,6 +1082,62 @@ impl<'a> SearchBuilder<'a> {
     }
 }
 
+/// Search results bundled with the context they were produced in.
+///
+/// Returned by [`SearchBuilder::execute()`]. Keeping the query alongside the
+/// results allows re-sorting them by relevance later, and the source lists help
+/// when debugging why a search came back empty.
+///
+/// # Fields
+///
+/// * `manga` - The merged results of all sources that succeeded
+/// * `query` - The query the search was run with
+/// * `sources_queried` - IDs of every source consulted, including failed ones
+/// * `errors` - Source ID and error message for each source that failed
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    pub manga: Vec<Manga>,
+    pub query: String,
+    pub sources_queried: Vec<String>,
+    pub errors: Vec<(String, String)>,
+}
+
+impl SearchResult {
+    /// Removes duplicate results according to `mode`, keeping the first occurrence.
+    pub fn dedupe(mut self, mode: DedupeMode) -> Self {
+        self.manga = mode.apply(self.manga);
+        self
+    }
+
+    /// Sorts the results by relevance to the original query, best match first.
+    ///
+    /// See [`SearchResultExt::sort_by_query_relevance()`].
+    pub fn sorted(mut self) -> Self {
+        self.manga = self.manga.sort_by_query_relevance(&self.query);
+        self
+    }
+}
+
+/// Wraps plain results, e.g. from [`SearchBuilder::flatten()`], with an empty
+/// query and no source or error information.
+impl From<Vec<Manga>> for SearchResult {
+    fn from(manga: Vec<Manga>) -> Self {
+        SearchResult {
+            manga,
+            query: String::new(),
+            sources_queried: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+}
+
+/// Unwraps the results, discarding the query, sources and errors.
+impl From<SearchResult> for Vec<Manga> {
+    fn from(result: SearchResult) -> Self {
+        result.manga
+    }
+}
+
 /// Extension trait providing additional processing methods for search results.
 ///
 /// This trait adds useful post-processing methods to `Vec<Manga>` that help you
@@ -431,11 +1198,62 @@ pub trait SearchResultExt {
     /// ```
     fn filter_popular(self, min_popularity_score: usize) -> Self;
 
+    /// Counts the results [`filter_popular()`](SearchResultExt::filter_popular) would keep.
+    ///
+    /// Borrows the results instead of consuming them, so a threshold can be
+    /// previewed without cloning the list.
+    ///
+    /// # Parameters
+    ///
+    /// * `min_popularity_score` - Minimum score required (0-7 range)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # use tosho::error::Result;
+    /// # async fn example() -> Result<()> {
+    /// # let sources = Sources::new();
+    ///
+    /// let results = sources.search("manga").flatten().await?;
+    /// if results.count_popular(4) >= 10 {
+    ///     let results = results.filter_popular(4);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn count_popular(&self, min_popularity_score: usize) -> usize;
+
+    /// Keeps only manga that have a cover image.
+    ///
+    /// Entries without a cover look broken in grid layouts. A `cover_url` that
+    /// is `None` or blank counts as missing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # use tosho::error::Result;
+    /// # async fn example() -> Result<()> {
+    /// # let sources = Sources::new();
+    ///
+    /// let grid_items = sources
+    ///     .search("manga")
+    ///     .flatten()
+    ///     .await?
+    ///     .filter_has_cover();
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn filter_has_cover(self) -> Self;
+
     /// Removes duplicate manga entries based on title.
     ///
-    /// This method keeps the first occurrence of each manga title (case-insensitive)
-    /// and removes subsequent duplicates. This is useful when searching multiple
-    /// sources that may have the same manga.
+    /// This method keeps the first occurrence of each manga title and removes
+    /// subsequent duplicates. Titles are compared by their [`normalize_title`]
+    /// form, so case, accents and suffixes like "(Official Colored)" don't
+    /// prevent a match. This is useful when searching multiple sources that may
+    /// have the same manga.
     ///
     /// # Examples
     ///
@@ -510,55 +1328,146 @@ pub trait SearchResultExt {
     /// # }
     /// ```
     fn sort_by_query_relevance(self, query: &str) -> Self;
+
+    /// Returns the score [`sort_by_query_relevance()`](SearchResultExt::sort_by_query_relevance)
+    /// assigns to each result, without reordering anything.
+    ///
+    /// Higher scores sort first; ties are broken by shorter title.
+    ///
+    /// # Parameters
+    ///
+    /// * `query` - The original search query to match against
+    ///
+    /// # Returns
+    ///
+    /// `(title, score)` pairs in the current order of the results.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # use tosho::error::Result;
+    /// # async fn example() -> Result<()> {
+    /// # let sources = Sources::new();
+    ///
+    /// let results = sources.search("one piece").flatten().await?;
+    /// for (title, score) in results.relevance_scores("one piece") {
+    ///     println!("{:>4} {}", score, title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn relevance_scores(&self, query: &str) -> Vec<(String, u32)>;
+
+    /// Returns a single page of the results.
+    ///
+    /// This is client-side pagination for result sets that were already fetched,
+    /// complementing the server-side `offset`/`limit` search parameters. Pages
+    /// are numbered from 1; page 0 is treated as the first page. Out-of-range
+    /// pages, and a `per_page` of 0, yield an empty vector.
+    ///
+    /// # Parameters
+    ///
+    /// * `page` - The 1-based page number
+    /// * `per_page` - Number of results per page
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # use tosho::error::Result;
+    /// # async fn example() -> Result<()> {
+    /// # let sources = Sources::new();
+    ///
+    /// let results = sources.search("manga").flatten().await?;
+    /// let second_page = results.paginate(2, 20);  // Results 21-40
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn paginate(self, page: usize, per_page: usize) -> Self;
+
+    /// Partitions the results by the source they came from.
+    ///
+    /// This recovers the grouping of [`SearchBuilder::group()`] after results were
+    /// flattened, e.g. to render one section per source. Each group keeps the
+    /// relative order the manga had in the original list.
+    ///
+    /// # Returns
+    ///
+    /// A map from [`Manga::source_id`] to that source's manga, ordered by source ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # use tosho::error::Result;
+    /// # async fn example() -> Result<()> {
+    /// # let sources = Sources::new();
+    ///
+    /// let results = sources.search("one piece").flatten().await?.sort_by_relevance();
+    /// for (source_id, manga) in results.group_by_source() {
+    ///     println!("{}: {} results", source_id, manga.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn group_by_source(self) -> BTreeMap<String, Vec<Manga>>;
+
+    /// Reorders the results round-robin across their sources.
+    ///
+    /// Flattened results arrive source by source, so the first screen of a list
+    /// is often filled by a single source. This takes the first result of each
+    /// source, then the second of each, and so on. Sources keep the order in
+    /// which they first appear, each source's results keep their relative
+    /// order, and results of longer sources are appended once the shorter ones
+    /// run out.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tosho::prelude::*;
+    /// # use tosho::error::Result;
+    /// # async fn example() -> Result<()> {
+    /// # let sources = Sources::new();
+    ///
+    /// let balanced = sources
+    ///     .search("manga")
+    ///     .flatten()
+    ///     .await?
+    ///     .interleave_by_source()
+    ///     .paginate(1, 20);  // First page shows every source
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn interleave_by_source(self) -> Self;
 }
 
 impl SearchResultExt for Vec<Manga> {
     fn filter_popular(self, min_popularity_score: usize) -> Self {
-        // Filter manga based on popularity heuristics since we don't have direct chapter counts
-        // We use a scoring system based on available metadata:
-        // - Has description: +2 points
-        // - Has authors: +1 point
-        // - Has cover image: +1 point
-        // - Has 3+ tags: +1 point
-        // - Has 5+ tags: +2 points (additional point)
         self.into_iter()
-            .filter(|manga| {
-                let mut score = 0;
-
-                // Description indicates well-documented manga
-                if manga.description.is_some()
-                    && !manga.description.as_ref().unwrap().trim().is_empty()
-                {
-                    score += 2;
-                }
-
-                // Authors listed indicates properly catalogued manga
-                if !manga.authors.is_empty() {
-                    score += 1;
-                }
-
-                // Cover image indicates higher quality entry
-                if manga.cover_url.is_some() {
-                    score += 1;
-                }
+            .filter(|manga| popularity_score(manga) >= min_popularity_score)
+            .collect()
+    }
 
-                // Well-tagged manga tend to be more popular/complete
-                let tag_count = manga.tags.len();
-                if tag_count >= 3 {
-                    score += 1;
-                }
-                if tag_count >= 5 {
-                    score += 1; // Additional point for very well-tagged manga
-                }
+    fn count_popular(&self, min_popularity_score: usize) -> usize {
+        self.iter()
+            .filter(|manga| popularity_score(manga) >= min_popularity_score)
+            .count()
+    }
 
-                score >= min_popularity_score
-            })
-            .collect()
+    fn filter_has_cover(mut self) -> Self {
+        self.retain(|manga| {
+            manga
+                .cover_url
+                .as_deref()
+                .is_some_and(|url| !url.trim().is_empty())
+        });
+        self
     }
 
     fn dedupe_by_title(mut self) -> Self {
         let mut seen = std::collections::HashSet::new();
-        self.retain(|manga| seen.insert(manga.title.to_lowercase()));
+        self.retain(|manga| seen.insert(normalize_title(&manga.title)));
         self
     }
 
@@ -576,6 +1485,22 @@ impl SearchResultExt for Vec<Manga> {
         self
     }
 
+    fn relevance_scores(&self, query: &str) -> Vec<(String, u32)> {
+        let query_lower = query.to_lowercase();
+        self.iter()
+            .map(|manga| {
+                let score = calculate_query_relevance_score(
+                    &manga.title,
+                    &manga.description,
+                    &manga.tags,
+                    &manga.authors,
+                    &query_lower,
+                );
+                (manga.title.clone(), score)
+            })
+            .collect()
+    }
+
     fn sort_by_query_relevance(mut self, query: &str) -> Self {
         // Query-aware relevance scoring
         let query_lower = query.to_lowercase();
@@ -602,6 +1527,106 @@ impl SearchResultExt for Vec<Manga> {
         });
         self
     }
+
+    fn paginate(self, page: usize, per_page: usize) -> Self {
+        let start = page.saturating_sub(1).saturating_mul(per_page);
+        self.into_iter().skip(start).take(per_page).collect()
+    }
+
+    fn group_by_source(self) -> BTreeMap<String, Vec<Manga>> {
+        let mut groups: BTreeMap<String, Vec<Manga>> = BTreeMap::new();
+        for manga in self {
+            groups
+                .entry(manga.source_id.clone())
+                .or_default()
+                .push(manga);
+        }
+        groups
+    }
+
+    fn interleave_by_source(self) -> Self {
+        let total = self.len();
+
+        // Queues in order of each source's first appearance
+        let mut queues: Vec<std::collections::VecDeque<Manga>> = Vec::new();
+        let mut positions = std::collections::HashMap::new();
+        for manga in self {
+            let index = *positions.entry(manga.source_id.clone()).or_insert_with(|| {
+                queues.push(Default::default());
+                queues.len() - 1
+            });
+            queues[index].push_back(manga);
+        }
+
+        let mut interleaved = Vec::with_capacity(total);
+        while interleaved.len() < total {
+            for queue in &mut queues {
+                if let Some(manga) = queue.pop_front() {
+                    interleaved.push(manga);
+                }
+            }
+        }
+        interleaved
+    }
+}
+
+/// Similarity of two titles from 0.0 to 1.0, based on their Levenshtein distance
+fn title_similarity(a: &[char], b: &[char]) -> f64 {
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    1.0 - previous[b.len()] as f64 / longest as f64
+}
+
+/// Heuristic popularity score used by [`SearchResultExt::filter_popular()`].
+///
+/// We don't have direct chapter counts, so the score is based on available metadata:
+/// - Has description: +2 points
+/// - Has authors: +1 point
+/// - Has cover image: +1 point
+/// - Has 3+ tags: +1 point
+/// - Has 5+ tags: +2 points (additional point)
+fn popularity_score(manga: &Manga) -> usize {
+    let mut score = 0;
+
+    // Description indicates well-documented manga
+    if manga.description.is_some() && !manga.description.as_ref().unwrap().trim().is_empty() {
+        score += 2;
+    }
+
+    // Authors listed indicates properly catalogued manga
+    if !manga.authors.is_empty() {
+        score += 1;
+    }
+
+    // Cover image indicates higher quality entry
+    if manga.cover_url.is_some() {
+        score += 1;
+    }
+
+    // Well-tagged manga tend to be more popular/complete
+    let tag_count = manga.tags.len();
+    if tag_count >= 3 {
+        score += 1;
+    }
+    if tag_count >= 5 {
+        score += 1; // Additional point for very well-tagged manga
+    }
+
+    score
 }
 
 /// Calculate relevance score for a manga based on multiple factors
@@ -646,7 +1671,7 @@ fn calculate_relevance_score(
         score += 8; // Official versions
     }
 
-    if title.chars().all(|c| c.is_ascii()) {
+    if title.is_ascii() {
         score += 3; // ASCII titles often more accessible
     }
 
@@ -27,20 +27,39 @@
 //! ```
 
 use async_trait::async_trait;
-use futures::future;
+use futures::{StreamExt, future, stream};
 use std::{
-    collections::HashMap,
+    borrow::Cow,
+    collections::{HashMap, HashSet},
     path::{Path, PathBuf},
 };
+#[cfg(feature = "conversion")]
 use tokio::task::spawn_blocking;
+use tokio_util::sync::CancellationToken;
 
 use crate::{
     SearchParams,
+    download::{DownloadReport, NamingStrategy},
     error::Result,
+    net::{HttpClient, HttpStats},
     search::SearchBuilder,
-    types::{Chapter, ImageFormat, Manga},
+    types::{Chapter, ChapterUpdate, ImageFormat, Manga, PageInfo, SortOrder},
 };
 
+/// An optional search feature a [`Source`] may support.
+///
+/// # Variants
+///
+/// * `TagFiltering` - Honors [`SearchParams::include_tags`] and [`SearchParams::exclude_tags`]
+/// * `Sorting` - Honors [`SearchParams::sort_by`]
+/// * `Pagination` - Honors [`SearchParams::offset`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capability {
+    TagFiltering,
+    Sorting,
+    Pagination,
+}
+
 /// Trait that all manga sources must implement.
 ///
 /// The `Source` trait defines the interface for manga sources, providing methods
@@ -205,7 +224,6 @@ pub trait Source: Send + Sync {
     /// Retrieves the list of chapters for a specific manga.
     ///
     /// This method fetches all available chapters for the given manga ID.
-    /// Chapters should be returned in reading order (usually chronological).
     ///
     /// # Parameters
     ///
@@ -213,7 +231,9 @@ pub trait Source: Send + Sync {
     ///
     /// # Returns
     ///
-    /// A vector of [`Chapter`] objects for the manga, typically sorted by chapter number.
+    /// A vector of [`Chapter`] objects for the manga in ascending reading order,
+    /// i.e. sorted with [`Chapter::cmp_by_number`]: by chapter number, then by
+    /// volume for chapters sharing a number.
     ///
     /// # Errors
     ///
@@ -225,6 +245,8 @@ pub trait Source: Send + Sync {
     ///
     /// - Ensure all returned chapters have their `manga_id` and `source_id` fields set
     /// - Handle special chapters (like .5 chapters) using decimal numbers
+    /// - Sort with a stable sort such as [`slice::sort_by`] so chapters that compare
+    ///   equal keep the order the site lists them in
     /// - Consider caching chapter lists if the source supports it
     async fn get_chapters(&self, manga_id: &str) -> Result<Vec<Chapter>>;
 
@@ -254,12 +276,199 @@ pub trait Source: Send + Sync {
     /// - Consider implementing lazy loading for large chapters
     async fn get_pages(&self, chapter_id: &str) -> Result<Vec<String>>;
 
+    /// Retrieves the pages of a chapter together with any known metadata.
+    ///
+    /// Readers can use the dimensions to lay out pages before they load, and
+    /// the referer to fetch images from hosts that check it. The default
+    /// implementation wraps [`get_pages()`](Source::get_pages) and only fills
+    /// in the URLs; sources that know more should override this.
+    ///
+    /// # Parameters
+    ///
+    /// * `chapter_id` - The unique identifier of the chapter within this source
+    ///
+    /// # Returns
+    ///
+    /// One [`PageInfo`] per page, in reading order.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`get_pages()`](Source::get_pages).
+    async fn get_pages_detailed(&self, chapter_id: &str) -> Result<Vec<PageInfo>> {
+        let pages = self.get_pages(chapter_id).await?;
+        Ok(pages.into_iter().map(PageInfo::from).collect())
+    }
+
+    /// Returns the number of pages in a chapter.
+    ///
+    /// Lets UIs show a page count before the chapter is downloaded. The default
+    /// implementation resolves every page with [`get_pages()`](Source::get_pages)
+    /// and counts them; sources whose chapter metadata already includes the
+    /// count should override this with a cheaper lookup.
+    ///
+    /// # Parameters
+    ///
+    /// * `chapter_id` - The unique identifier of the chapter within this source
+    ///
+    /// # Errors
+    ///
+    /// Same as [`get_pages()`](Source::get_pages).
+    async fn get_page_count(&self, chapter_id: &str) -> Result<usize> {
+        Ok(self.get_pages(chapter_id).await?.len())
+    }
+
+    /// Fetches the most recently updated manga, for a "latest updates" feed.
+    ///
+    /// The default implementation runs [`search()`](Source::search) with an
+    /// empty query sorted by [`SortOrder::UpdatedAt`]. Sources with a dedicated
+    /// listing for recent uploads should override this.
+    ///
+    /// # Parameters
+    ///
+    /// * `limit` - Maximum number of manga to return
+    ///
+    /// # Returns
+    ///
+    /// A vector of [`Manga`] objects, most recently updated first.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`search()`](Source::search).
+    async fn latest_updates(&self, limit: usize) -> Result<Vec<Manga>> {
+        self.search(SearchParams {
+            limit: Some(limit),
+            sort_by: Some(SortOrder::UpdatedAt),
+            ..SearchParams::from("")
+        })
+        .await
+    }
+
+    /// Lists the optional search features this source honors.
+    ///
+    /// Sources may ignore [`SearchParams`] fields they can't express; this tells
+    /// callers which ones actually take effect, so searches that depend on them
+    /// can skip other sources (see
+    /// [`SearchBuilder::require_capability()`](crate::SearchBuilder::require_capability)).
+    /// The default claims no capabilities.
+    fn capabilities(&self) -> &'static [Capability] {
+        &[]
+    }
+
+    /// Returns the shortest query, in characters, this source accepts.
+    ///
+    /// Some sites answer very short queries with their entire catalog, which is
+    /// slow and rarely useful. Such sources reject shorter queries from
+    /// [`search()`](Source::search) with an [`Error::Parse`](crate::Error::Parse);
+    /// when searching through [`Sources`], that error is reported for the source
+    /// like any other failure. Surrounding whitespace doesn't count.
+    ///
+    /// The default is 0, so empty queries stay available for browsing (see
+    /// [`latest_updates()`](Source::latest_updates)).
+    fn min_query_length(&self) -> usize {
+        0
+    }
+
+    /// Describes the requests a search would make, without sending them.
+    ///
+    /// This is a debugging aid: when a source unexpectedly returns nothing,
+    /// the URLs show exactly what was asked of the site. See
+    /// [`SearchBuilder::explain()`](crate::SearchBuilder::explain) for the
+    /// multi-source version.
+    ///
+    /// # Parameters
+    ///
+    /// * `params` - The search parameters to describe
+    ///
+    /// # Returns
+    ///
+    /// `(label, url)` pairs, one per request the search would perform. The
+    /// default returns an empty vector, meaning the source can't describe its
+    /// requests. A source may add an entry for input it can't resolve yet
+    /// (MangaDex lists `"unresolved tags"` before its tag IDs are cached),
+    /// so a filter never silently drops out of the description.
+    fn explain(&self, params: &SearchParams) -> Vec<(String, String)> {
+        let _ = params;
+        Vec::new()
+    }
+
+    /// Returns `true` if the page URLs from [`get_pages()`](Source::get_pages)
+    /// stop working after a while.
+    ///
+    /// Some sources hand out signed image URLs that expire within minutes. For
+    /// those, the `download_chapter*` methods fetch fresh page URLs when a page
+    /// request fails with 403 Forbidden or 410 Gone, and retry the remaining
+    /// pages with them. The default implementation returns `false`.
+    fn page_urls_expire(&self) -> bool {
+        false
+    }
+
+    /// Returns the HTTP client this source sends its requests with.
+    ///
+    /// The `download_chapter*` methods and [`Sources::prefetch_covers()`]
+    /// fetch images through this client, so the headers, user agents,
+    /// timeouts, retries and [middleware](crate::net::RequestMiddleware)
+    /// configured for the source apply to image requests too. Chapter pages
+    /// skip the rate limit, since image hosts enforce their own. The default
+    /// implementation returns `None`, in which case images are fetched with a
+    /// plain [`HttpClient`].
+    fn http_client(&self) -> Option<&HttpClient> {
+        None
+    }
+
+    /// Returns the request counters of the HTTP client used by this source.
+    ///
+    /// The default implementation returns `None`. Sources built on
+    /// [`net::HttpClient`](crate::net::HttpClient) should override this to
+    /// expose [`HttpClient::stats()`](crate::net::HttpClient::stats), which
+    /// makes them show up in [`Sources::stats()`].
+    fn http_stats(&self) -> Option<HttpStats> {
+        None
+    }
+
+    /// Returns the delay between requests this source currently enforces, in milliseconds.
+    ///
+    /// The default implementation returns `None`, meaning the source doesn't
+    /// rate limit itself or can't report it. Sources built on
+    /// [`net::HttpClient`](crate::net::HttpClient) should override this to
+    /// return [`HttpClient::rate_limit_ms()`](crate::net::HttpClient::rate_limit_ms).
+    fn rate_limit_ms(&self) -> Option<u64> {
+        None
+    }
+
+    /// Changes the delay between requests of this source.
+    ///
+    /// Sources that override [`rate_limit_ms()`](Source::rate_limit_ms) should
+    /// override this too, usually with
+    /// [`HttpClient::set_rate_limit()`](crate::net::HttpClient::set_rate_limit).
+    /// See [`Sources::set_rate_limit()`].
+    ///
+    /// # Parameters
+    ///
+    /// * `delay_ms` - Minimum delay between requests in milliseconds
+    ///
+    /// # Errors
+    ///
+    /// The default implementation returns [`Error::Source`](crate::Error::Source),
+    /// since the source has no rate limit to change.
+    fn set_rate_limit_ms(&self, delay_ms: u64) -> Result<()> {
+        let _ = delay_ms;
+        Err(crate::Error::source(
+            self.id(),
+            "Rate limit is not configurable",
+        ))
+    }
+
     /// Downloads a chapter to the specified directory with a structured path.
     ///
     /// The default implementation creates a directory structure of:
-    /// `output_dir/manga_title/xx-yyy/zzz.ext`
+    /// `output_dir/manga_slug/xx-yyy/zzz.ext` (see [`Manga::slug()`]), with the
+    /// manga's metadata saved as `output_dir/manga_slug/metadata.json`,
     /// and can convert images to a specified format on the fly.
     ///
+    /// Pages are resolved with [`get_pages_detailed()`](Source::get_pages_detailed)
+    /// and fetched through [`http_client()`](Source::http_client), so a page's
+    /// referer is sent along with the source's own headers.
+    ///
     /// # Parameters
     ///
     /// * `manga` - The manga object, used for the top-level directory name.
@@ -270,6 +479,10 @@ pub trait Source: Send + Sync {
     /// # Returns
     ///
     /// The path to the downloaded chapter directory.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::Conversion`](crate::Error::Conversion) - If `image_format` is set but the `conversion` feature is disabled
     async fn download_chapter(
         &self,
         manga: &Manga,
@@ -277,107 +490,415 @@ pub trait Source: Send + Sync {
         output_dir: &Path,
         image_format: Option<ImageFormat>,
     ) -> Result<PathBuf> {
-        use crate::download::sanitize_filename;
-        use tokio::fs;
-
-        let pages = self.get_pages(&chapter.id).await?;
-        if pages.is_empty() {
-            return Err(crate::Error::source(
-                self.id(),
-                format!("No pages found for chapter {}", chapter.id),
-            ));
-        }
+        self.download_chapter_cancellable(
+            manga,
+            chapter,
+            output_dir,
+            image_format,
+            &CancellationToken::new(),
+        )
+        .await
+    }
 
-        // 1. Create the manga directory: /%MANGA_NAME%/
-        let manga_dir = output_dir.join(sanitize_filename(&manga.title));
-
-        // 2. Create the chapter directory: /%VOL%-%CH%/
-        let chapter_name = {
-            // Default to volume 0 if not present, and cast to u32 for padding.
-            let vol_num = chapter.volume.unwrap_or(0.0) as u32;
-
-            // Handle chapter numbers with decimals (e.g., 99.5) separately from integers.
-            let chapter_number_str = {
-                let fract = chapter.number.fract();
-                // Use a small tolerance for floating point comparison
-                if fract.abs() > 0.001 {
-                    // It has a decimal part, e.g., 99.5 -> "99.5"
-                    format!("{:.1}", chapter.number)
-                } else {
-                    // It's a whole number, e.g., 100.0 -> "100". Pad it.
-                    format!("{:03}", chapter.number as u32)
-                }
-            };
+    /// Downloads a chapter like [`download_chapter()`](Source::download_chapter),
+    /// stopping early when the given token is cancelled.
+    ///
+    /// The token is checked before every page and raced against each in-flight
+    /// page request, so cancellation takes effect without waiting for the rest
+    /// of the chapter. Pages written before cancellation are left on disk.
+    ///
+    /// # Parameters
+    ///
+    /// * `manga` - The manga object, used for the top-level directory name.
+    /// * `chapter` - The chapter object, used for volume/chapter numbers.
+    /// * `output_dir` - Base directory where the manga folder will be created.
+    /// * `image_format` - If Some, converts all downloaded images to this format. If None, saves in original format.
+    /// * `cancel` - Token that aborts the download when cancelled.
+    ///
+    /// # Returns
+    ///
+    /// The path to the downloaded chapter directory.
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::Other`](crate::Error::Other) - With the message `"cancelled"` if the token was cancelled
+    async fn download_chapter_cancellable(
+        &self,
+        manga: &Manga,
+        chapter: &Chapter,
+        output_dir: &Path,
+        image_format: Option<ImageFormat>,
+        cancel: &CancellationToken,
+    ) -> Result<PathBuf> {
+        download_chapter_pages(
+            self,
+            manga,
+            chapter,
+            output_dir,
+            PageOptions {
+                image_format,
+                naming: NamingStrategy::default(),
+                min_dimension: None,
+            },
+            cancel,
+        )
+        .await
+        .map(|report| report.chapter_dir)
+    }
 
-            format!("{:03}-{}", vol_num, chapter_number_str)
-        };
+    /// Downloads a chapter like [`download_chapter()`](Source::download_chapter),
+    /// naming page files according to the given strategy.
+    ///
+    /// # Parameters
+    ///
+    /// * `manga` - The manga object, used for the top-level directory name.
+    /// * `chapter` - The chapter object, used for volume/chapter numbers.
+    /// * `output_dir` - Base directory where the manga folder will be created.
+    /// * `image_format` - If Some, converts all downloaded images to this format. If None, saves in original format.
+    /// * `naming` - How page files inside the chapter directory are named.
+    ///
+    /// # Returns
+    ///
+    /// The path to the downloaded chapter directory.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use tosho::prelude::*;
+    /// use tosho::download::NamingStrategy;
+    /// use std::path::Path;
+    ///
+    /// # async fn example(source: &dyn Source, manga: &Manga, chapter: &Chapter) -> tosho::Result<()> {
+    /// // Keep the file names the source serves, e.g. "x1-b8f3e2.png"
+    /// let dir = source
+    ///     .download_chapter_named(manga, chapter, Path::new("./downloads"), None, NamingStrategy::PreserveOriginal)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn download_chapter_named(
+        &self,
+        manga: &Manga,
+        chapter: &Chapter,
+        output_dir: &Path,
+        image_format: Option<ImageFormat>,
+        naming: NamingStrategy,
+    ) -> Result<PathBuf> {
+        download_chapter_pages(
+            self,
+            manga,
+            chapter,
+            output_dir,
+            PageOptions {
+                image_format,
+                naming,
+                min_dimension: None,
+            },
+            &CancellationToken::new(),
+        )
+        .await
+        .map(|report| report.chapter_dir)
+    }
+
+    /// Downloads a chapter like [`download_chapter()`](Source::download_chapter),
+    /// checking every page before it is saved.
+    ///
+    /// Each page is decoded with [`validate_page_image()`](crate::download::validate_page_image);
+    /// pages that aren't images or are smaller than `min_dimension` in either
+    /// direction (HTML error pages, tracking pixels) are skipped and listed in the
+    /// returned report instead of failing the download. Saved pages keep their
+    /// original page numbers, so a rejected page leaves a gap.
+    ///
+    /// Requires the `conversion` feature.
+    ///
+    /// # Parameters
+    ///
+    /// * `manga` - The manga object, used for the top-level directory name.
+    /// * `chapter` - The chapter object, used for volume/chapter numbers.
+    /// * `output_dir` - Base directory where the manga folder will be created.
+    /// * `image_format` - If Some, converts all downloaded images to this format. If None, saves in original format.
+    /// * `min_dimension` - Minimum width and height in pixels, e.g. [`MIN_PAGE_DIMENSION`](crate::download::MIN_PAGE_DIMENSION).
+    ///
+    /// # Returns
+    ///
+    /// A [`DownloadReport`] with the chapter directory and any rejected pages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use tosho::prelude::*;
+    /// use tosho::download::MIN_PAGE_DIMENSION;
+    /// use std::path::Path;
+    ///
+    /// # async fn example(source: &dyn Source, manga: &Manga, chapter: &Chapter) -> tosho::Result<()> {
+    /// let report = source
+    ///     .download_chapter_validated(manga, chapter, Path::new("./downloads"), None, MIN_PAGE_DIMENSION)
+    ///     .await?;
+    /// for page in &report.invalid_pages {
+    ///     println!("Page {} skipped: {}", page.page, page.reason);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "conversion")]
+    async fn download_chapter_validated(
+        &self,
+        manga: &Manga,
+        chapter: &Chapter,
+        output_dir: &Path,
+        image_format: Option<ImageFormat>,
+        min_dimension: u32,
+    ) -> Result<DownloadReport> {
+        download_chapter_pages(
+            self,
+            manga,
+            chapter,
+            output_dir,
+            PageOptions {
+                image_format,
+                naming: NamingStrategy::default(),
+                min_dimension: Some(min_dimension),
+            },
+            &CancellationToken::new(),
+        )
+        .await
+    }
+}
 
-        let chapter_dir = manga_dir.join(sanitize_filename(&chapter_name));
+/// How often a chapter download re-fetches expired page URLs before giving up.
+const MAX_PAGE_URL_REFRESHES: usize = 2;
 
-        fs::create_dir_all(&chapter_dir).await.map_err(|e| {
-            crate::Error::source(self.id(), format!("Failed to create directory: {}", e))
-        })?;
+/// How [`download_chapter_pages`] processes and names each page.
+struct PageOptions {
+    image_format: Option<ImageFormat>,
+    naming: NamingStrategy,
+    min_dimension: Option<u32>,
+}
 
-        // 3. Download, convert, and save pages
-        let client = reqwest::Client::new();
-        for (i, page_url) in pages.iter().enumerate() {
-            let response = client.get(page_url).send().await.map_err(|e| {
-                crate::Error::parse(format!("Failed to download page {}: {}", i + 1, e))
-            })?;
+/// Shared implementation behind the `download_chapter*` methods of [`Source`].
+async fn download_chapter_pages<S: Source + ?Sized>(
+    source: &S,
+    manga: &Manga,
+    chapter: &Chapter,
+    output_dir: &Path,
+    options: PageOptions,
+    cancel: &CancellationToken,
+) -> Result<DownloadReport> {
+    let PageOptions {
+        image_format,
+        naming,
+        min_dimension,
+    } = options;
+    use crate::download::chapter_dir;
+    use tokio::fs;
 
-            if !response.status().is_success() {
-                return Err(crate::Error::parse(format!(
-                    "Failed to download page {}: HTTP {}",
-                    i + 1,
-                    response.status()
-                )));
-            }
+    let cancelled = || crate::Error::Other("cancelled".to_string());
 
-            let image_bytes = response.bytes().await.map_err(|e| {
-                crate::Error::parse(format!("Failed to read page {} data: {}", i + 1, e))
-            })?;
+    if cancel.is_cancelled() {
+        return Err(cancelled());
+    }
+
+    #[cfg(not(feature = "conversion"))]
+    if image_format.is_some() || min_dimension.is_some() {
+        return Err(crate::Error::conversion(CONVERSION_DISABLED));
+    }
+
+    let mut pages = source.get_pages_detailed(&chapter.id).await?;
+    if pages.is_empty() {
+        return Err(crate::Error::source(
+            source.id(),
+            format!("No pages found for chapter {}", chapter.id),
+        ));
+    }
+
+    // 1. Resolve the chapter directory: /%MANGA_SLUG%/%VOL%-%CH%/
+    let chapter_dir = chapter_dir(output_dir, manga, chapter);
+
+    fs::create_dir_all(&chapter_dir).await.map_err(|e| {
+        crate::Error::source(source.id(), format!("Failed to create directory: {}", e))
+    })?;
 
-            let page_num = i + 1;
-            let final_image_data;
-            let final_extension: String;
+    // Keep the manga's metadata next to the slug-named chapter directories
+    if let Some(manga_dir) = chapter_dir.parent() {
+        crate::download::write_manga_metadata(manga, manga_dir).await?;
+    }
+
+    let mut report = DownloadReport {
+        chapter_dir,
+        saved_pages: 0,
+        invalid_pages: Vec::new(),
+    };
+
+    // Pad page numbers wide enough that every file name of the chapter sorts lexically
+    let naming = naming.for_page_count(pages.len());
+    let number_width = crate::download::page_number_width(pages.len());
+    let mut saved_names = HashSet::new();
+
+    // 2. Download, validate, convert, and save pages
+    let client = image_client(source);
+    let mut refreshes_left = if source.page_urls_expire() {
+        MAX_PAGE_URL_REFRESHES
+    } else {
+        0
+    };
+    for i in 0..pages.len() {
+        if cancel.is_cancelled() {
+            return Err(cancelled());
+        }
 
-            if let Some(format) = image_format {
-                // Convert the image
-                final_image_data = spawn_blocking(move || -> Result<Vec<u8>> {
-                    let img = image::load_from_memory(&image_bytes).map_err(|e| {
-                        crate::Error::parse(format!("Failed to decode image: {}", e))
-                    })?;
+        let image_bytes = loop {
+            // Hosts that check the referer only serve pages to their reader
+            let page_client = match &pages[i].referer {
+                Some(referer) => Cow::Owned(client.clone().with_header("Referer", referer)),
+                None => Cow::Borrowed(&client),
+            };
+            let fetch = page_client.get(&pages[i].url);
 
-                    let mut buffer = std::io::Cursor::new(Vec::new());
+            let result = tokio::select! {
+                biased;
+                _ = cancel.cancelled() => return Err(cancelled()),
+                bytes = fetch => bytes,
+            };
 
-                    match format {
-                        ImageFormat::Jpeg => img.write_to(&mut buffer, image::ImageFormat::Jpeg)?,
-                        ImageFormat::Png => img.write_to(&mut buffer, image::ImageFormat::Png)?,
-                        ImageFormat::WebP => img.write_to(&mut buffer, image::ImageFormat::WebP)?,
-                        ImageFormat::Avif => img.write_to(&mut buffer, image::ImageFormat::Avif)?,
+            match result {
+                // The page URLs expired since they were fetched; get fresh ones
+                // and carry on with the remaining pages
+                Err(crate::Error::Source {
+                    status: Some(403 | 410),
+                    ..
+                }) if refreshes_left > 0 => {
+                    refreshes_left -= 1;
+                    let fresh = source.get_pages_detailed(&chapter.id).await?;
+                    if fresh.len() != pages.len() {
+                        return Err(crate::Error::source(
+                            source.id(),
+                            format!(
+                                "Page list of chapter {} changed during download",
+                                chapter.id
+                            ),
+                        ));
                     }
-                    Ok(buffer.into_inner())
-                })
-                .await??;
-                final_extension = format.extension().to_string();
-            } else {
-                // Save in original format
-                final_image_data = image_bytes.to_vec();
-                let page_url_clone = page_url.clone();
-                final_extension = crate::download::extract_extension(&page_url_clone)
-                    .unwrap_or_else(|| "jpg".to_string());
+                    pages = fresh;
+                }
+                result => break result?,
+            }
+        };
+
+        let page_url = &pages[i].url;
+        let page_num = i + 1;
+
+        #[cfg(feature = "conversion")]
+        if let Some(min_dimension) = min_dimension {
+            let data = image_bytes.clone();
+            let validation =
+                spawn_blocking(move || crate::download::validate_page_image(&data, min_dimension))
+                    .await
+                    .map_err(|e| crate::Error::parse(format!("Validation task failed: {}", e)))?;
+
+            if let Err(e) = validation {
+                report.invalid_pages.push(crate::download::InvalidPage {
+                    page: page_num,
+                    url: page_url.clone(),
+                    reason: e.to_string(),
+                });
+                continue;
             }
+        }
+
+        let final_image_data;
+        let final_extension: String;
+
+        if let Some(format) = image_format {
+            // Convert the image
+            final_image_data = convert_image(image_bytes, format).await?;
+            final_extension = format.extension().to_string();
+        } else {
+            // Save in original format
+            final_image_data = image_bytes.to_vec();
+            let page_url_clone = page_url.clone();
+            final_extension = crate::download::extract_extension(&page_url_clone)
+                .unwrap_or_else(|| "jpg".to_string());
+        }
 
-            // 4. Construct filename: /%PAGE_NUM%.ext
-            let filename = format!("{:03}.{}", page_num, final_extension.clone());
-            let filepath = chapter_dir.join(filename);
+        // 3. Construct filename according to the naming strategy. CDNs may
+        // reuse basenames across paths, so a name already taken by an earlier
+        // page gets the page number as a prefix instead of overwriting it.
+        let mut filename = naming.file_name(page_num, page_url, &final_extension);
+        if saved_names.contains(&filename) {
+            filename = format!("{:0width$}-{}", page_num, filename, width = number_width);
+        }
+        saved_names.insert(filename.clone());
+        let filepath = report.chapter_dir.join(filename);
 
-            fs::write(&filepath, &final_image_data).await.map_err(|e| {
-                crate::Error::source(self.id(), format!("Failed to write file: {}", e))
+        crate::download::write_file_atomic(&filepath, &final_image_data)
+            .await
+            .map_err(|e| {
+                crate::Error::source(source.id(), format!("Failed to write file: {}", e))
             })?;
+        report.saved_pages += 1;
+    }
+    Ok(report)
+}
+
+/// The client used to fetch a source's images.
+///
+/// Copies the source's own client, if it exposes one, so its headers and
+/// middleware apply. Image CDNs rate limit on their own, so requests aren't
+/// delayed, but still get the client's 429/Retry-After handling.
+pub(crate) fn image_client<S: Source + ?Sized>(source: &S) -> HttpClient {
+    source
+        .http_client()
+        .cloned()
+        .unwrap_or_else(|| HttpClient::new(source.id()))
+        .with_rate_limit(0)
+}
+
+/// Re-encodes downloaded page data into the requested image format.
+#[cfg(feature = "conversion")]
+async fn convert_image(image_bytes: bytes::Bytes, format: ImageFormat) -> Result<Vec<u8>> {
+    spawn_blocking(move || -> Result<Vec<u8>> {
+        let img = image::load_from_memory(&image_bytes)
+            .map_err(|e| crate::Error::parse(format!("Failed to decode image: {}", e)))?;
+
+        let mut buffer = std::io::Cursor::new(Vec::new());
+
+        match format {
+            ImageFormat::Jpeg => img.write_to(&mut buffer, image::ImageFormat::Jpeg)?,
+            ImageFormat::Png => img.write_to(&mut buffer, image::ImageFormat::Png)?,
+            ImageFormat::WebP => img.write_to(&mut buffer, image::ImageFormat::WebP)?,
+            ImageFormat::Avif => img.write_to(&mut buffer, image::ImageFormat::Avif)?,
         }
-        Ok(chapter_dir)
+        Ok(buffer.into_inner())
+    })
+    .await?
+}
+
+#[cfg(not(feature = "conversion"))]
+const CONVERSION_DISABLED: &str = "Image conversion requires the `conversion` feature";
+
+/// Without the `conversion` feature, pages can only be saved in their original format.
+#[cfg(not(feature = "conversion"))]
+async fn convert_image(_image_bytes: bytes::Bytes, _format: ImageFormat) -> Result<Vec<u8>> {
+    Err(crate::Error::conversion(CONVERSION_DISABLED))
+}
+
+/// Searches a single source, holding it to the requested per-source limit.
+///
+/// Sources that can only fetch whole result pages may return more than
+/// [`SearchParams::limit`] asks for, so the results are truncated here. Every
+/// result is also tagged with the source's ID.
+pub(crate) async fn search_source(source: &dyn Source, params: SearchParams) -> Result<Vec<Manga>> {
+    let limit = params.limit;
+    let mut manga = source.search(params).await?;
+
+    if let Some(limit) = limit {
+        manga.truncate(limit);
     }
+    for m in &mut manga {
+        m.source_id = source.id().to_string();
+    }
+
+    Ok(manga)
 }
 
 /// A collection of manga sources with convenience methods for management and aggregation.
@@ -418,6 +939,7 @@ pub trait Source: Send + Sync {
 /// ```
 pub struct Sources {
     sources: Vec<Box<dyn Source>>,
+    enabled: Vec<bool>,
     by_id: HashMap<String, usize>,
 }
 
@@ -436,10 +958,39 @@ impl Sources {
     pub fn new() -> Self {
         Self {
             sources: Vec::new(),
+            enabled: Vec::new(),
             by_id: HashMap::new(),
         }
     }
 
+    /// Creates a source collection with every source compiled into this build.
+    ///
+    /// Which sources are registered depends on the enabled feature flags
+    /// (`source-mangadex`, `source-kissmanga`, or `all-sources`). With no source
+    /// features enabled the collection is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::prelude::*;
+    ///
+    /// let sources = Sources::with_all_enabled();
+    /// # #[cfg(feature = "source-mangadex")]
+    /// assert!(sources.get("mgd").is_some());
+    /// ```
+    pub fn with_all_enabled() -> Self {
+        #[allow(unused_mut)]
+        let mut sources = Self::new();
+
+        #[cfg(feature = "source-mangadex")]
+        sources.add(crate::sources::MangaDexSource::new());
+
+        #[cfg(feature = "source-kissmanga")]
+        sources.add(crate::sources::KissMangaSource::new());
+
+        sources
+    }
+
     /// Starts a fluent search across all sources.
     ///
     /// This method returns a [`SearchBuilder`] that allows you to chain search
@@ -476,10 +1027,45 @@ impl Sources {
         SearchBuilder::new(self, query)
     }
 
+    /// Starts a fluent search across all sources from prebuilt parameters.
+    ///
+    /// Use this when the [`SearchParams`] already exist, for example when
+    /// they were deserialized from a saved preset. The returned builder can
+    /// still be refined before it is executed.
+    ///
+    /// # Parameters
+    ///
+    /// * `params` - The search parameters to seed the builder with
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::prelude::*;
+    /// use tosho::types::SearchParamsBuilder;
+    /// use tosho::error::Result;
+    ///
+    /// # async fn example() -> Result<()> {
+    /// let sources = Sources::new();
+    /// let preset = SearchParamsBuilder::default()
+    ///     .query("one piece".to_string())
+    ///     .limit(Some(20))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let results = sources.search_with(preset).offset(20).flatten().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_with(&self, params: SearchParams) -> SearchBuilder<'_> {
+        SearchBuilder::with_params(self, params)
+    }
+
     /// Adds a source to the collection.
     ///
     /// The source is added to the internal collection and indexed by its ID
-    /// for fast retrieval. Returns a mutable reference to self for chaining.
+    /// for fast retrieval. The index ignores ASCII case, so a source is found
+    /// whichever casing is used to look it up. Returns a mutable reference to
+    /// self for chaining.
     ///
     /// # Parameters
     ///
@@ -497,36 +1083,113 @@ impl Sources {
     /// // println!("Added {} sources", sources.len());
     /// ```
     pub fn add(&mut self, source: impl Source + 'static) -> &mut Self {
-        let id = source.id().to_string();
-        let index = self.sources.len();
-        self.sources.push(Box::new(source));
-        self.by_id.insert(id, index);
-        self
+        self.add_boxed(Box::new(source))
     }
 
-    /// Retrieves a source by its ID.
-    ///
-    /// # Parameters
+    /// Adds an already boxed source to the collection.
     ///
-    /// * `id` - The unique identifier of the source
+    /// Like [`add()`](Sources::add), for sources whose concrete type is only
+    /// known at runtime, such as those returned by
+    /// [`build_by_id()`](crate::sources::build_by_id).
     ///
-    /// # Returns
+    /// # Parameters
     ///
-    /// * `Some(&dyn Source)` - Reference to the source if found
-    /// * `None` - If no source with the given ID exists
+    /// * `source` - The boxed source to add
     ///
     /// # Examples
     ///
     /// ```rust
     /// use tosho::prelude::*;
-    /// use tosho::error::Result;
-    ///
-    /// # async fn example() -> Result<()> {
-    /// let sources = Sources::new();
     ///
-    /// if let Some(source) = sources.get("mgd") {
-    ///     println!("Found source: {}", source.name());
-    ///     let chapters = source.get_chapters("manga_id").await?;
+    /// let mut sources = Sources::new();
+    /// for id in ["mgd", "kmg"] {
+    ///     if let Some(source) = tosho::sources::build_by_id(id) {
+    ///         sources.add_boxed(source);
+    ///     }
+    /// }
+    /// ```
+    pub fn add_boxed(&mut self, source: Box<dyn Source>) -> &mut Self {
+        let id = source.id().to_ascii_lowercase();
+        let index = self.sources.len();
+        self.sources.push(source);
+        self.enabled.push(true);
+        self.by_id.insert(id, index);
+        self
+    }
+
+    /// Enables or disables a registered source.
+    ///
+    /// Disabled sources stay registered but are skipped by every aggregated
+    /// operation: searches across sources ([`search()`](Sources::search),
+    /// the `search_all_*` methods) and
+    /// [`latest_updates_all()`](Sources::latest_updates_all). They can still be
+    /// reached by ID, e.g. through [`get()`](Sources::get) or
+    /// [`SearchBuilder::from_source()`]. Sources are enabled when added.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The ID of the source, ignoring ASCII case
+    /// * `enabled` - Whether aggregated operations should use the source
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`](crate::Error::NotFound) if no source has the given ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::prelude::*;
+    ///
+    /// let mut sources = Sources::with_all_enabled();
+    /// # #[cfg(feature = "source-mangadex")]
+    /// # {
+    /// sources.set_enabled("mgd", false).unwrap();
+    /// assert!(!sources.is_enabled("mgd"));
+    /// # }
+    /// ```
+    pub fn set_enabled(&mut self, id: &str, enabled: bool) -> Result<()> {
+        let index = *self
+            .by_id
+            .get(&id.to_ascii_lowercase())
+            .ok_or_else(|| crate::Error::not_found(format!("Source: {}", id)))?;
+        self.enabled[index] = enabled;
+        Ok(())
+    }
+
+    /// Returns `true` if a source with the given ID is registered and enabled.
+    ///
+    /// See [`set_enabled()`](Sources::set_enabled).
+    pub fn is_enabled(&self, id: &str) -> bool {
+        self.by_id
+            .get(&id.to_ascii_lowercase())
+            .is_some_and(|&index| self.enabled[index])
+    }
+
+    /// Retrieves a source by its ID.
+    ///
+    /// The lookup ignores ASCII case: `"MGD"` finds the source with ID `"mgd"`.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The unique identifier of the source
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&dyn Source)` - Reference to the source if found
+    /// * `None` - If no source with the given ID exists
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::prelude::*;
+    /// use tosho::error::Result;
+    ///
+    /// # async fn example() -> Result<()> {
+    /// let sources = Sources::new();
+    ///
+    /// if let Some(source) = sources.get("mgd") {
+    ///     println!("Found source: {}", source.name());
+    ///     let chapters = source.get_chapters("manga_id").await?;
     /// } else {
     ///     println!("Source not found");
     /// }
@@ -535,11 +1198,39 @@ impl Sources {
     /// ```
     pub fn get(&self, id: &str) -> Option<&dyn Source> {
         self.by_id
-            .get(id)
+            .get(&id.to_ascii_lowercase())
             .and_then(|&index| self.sources.get(index))
             .map(|s| s.as_ref())
     }
 
+    /// Retrieves a source by its ID, treating a missing source as an error.
+    ///
+    /// Like [`get()`](Sources::get), for callers that can't continue without
+    /// the source.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The unique identifier of the source
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NotFound`](crate::Error::NotFound) - If no source with the given ID exists;
+    ///   the message includes the ID
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::prelude::*;
+    ///
+    /// let sources = Sources::new();
+    /// let error = sources.get_or_err("missing").err().unwrap();
+    /// assert!(error.to_string().contains("missing"));
+    /// ```
+    pub fn get_or_err(&self, id: &str) -> Result<&dyn Source> {
+        self.get(id)
+            .ok_or_else(|| crate::Error::not_found(format!("Source: {}", id)))
+    }
+
     /// Returns a list of all source IDs in the collection.
     ///
     /// # Returns
@@ -568,6 +1259,10 @@ impl Sources {
     /// and returns the results grouped by source ID. Each source's result is
     /// returned separately, allowing you to handle successes and failures individually.
     ///
+    /// Each source's results are truncated to [`SearchParams::limit`], even if
+    /// the source returned more. The limit applies per source; cap the merged
+    /// list with [`SearchBuilder::total_limit()`].
+    ///
     /// # Parameters
     ///
     /// * `params` - Search parameters to use for all sources
@@ -602,19 +1297,112 @@ impl Sources {
         &self,
         params: SearchParams,
     ) -> Vec<(String, Result<Vec<Manga>>)> {
-        let futures = self.sources.iter().map(|source| {
-            let params = params.clone();
-            async move {
-                let source_id = source.id().to_string();
-                let result = source.search(params).await.map(|mut manga| {
-                    // Add source_id to each manga
-                    for m in &mut manga {
-                        m.source_id = source_id.clone();
-                    }
-                    manga
-                });
-                (source_id, result)
+        self.search_capable_grouped(&params, &[]).await
+    }
+
+    /// Searches all sources, retrying only the ones that failed transiently.
+    ///
+    /// Like [`search_all_grouped()`](Sources::search_all_grouped), but after
+    /// the first attempt every source whose error is
+    /// [retryable](crate::Error::is_retryable) is searched again, up to
+    /// `retries` more times. Sources that succeeded, or failed in a way a retry
+    /// can't fix, keep their first result and are not queried again, so
+    /// retrying doesn't spend their rate limit.
+    ///
+    /// # Parameters
+    ///
+    /// * `params` - Search parameters to use for all sources
+    /// * `retries` - How many more times a failing source may be searched
+    ///
+    /// # Returns
+    ///
+    /// One `(source_id, result)` pair per source, in registration order, with
+    /// each source's last result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::prelude::*;
+    ///
+    /// # async fn example() {
+    /// let sources = Sources::new();
+    /// let params = SearchParams::from("one piece");
+    ///
+    /// let grouped = sources.search_all_grouped_retrying(params, 2).await;
+    /// for (source_id, result) in grouped {
+    ///     if let Err(e) = result {
+    ///         println!("{} still failing: {}", source_id, e);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn search_all_grouped_retrying(
+        &self,
+        params: SearchParams,
+        retries: usize,
+    ) -> Vec<(String, Result<Vec<Manga>>)> {
+        let candidates: Vec<&dyn Source> = self.capable_sources(&[]).collect();
+        let mut grouped = Self::search_each(candidates.iter().copied(), &params).await;
+
+        for _ in 0..retries {
+            let failed: Vec<usize> = grouped
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, result))| result.as_ref().is_err_and(|e| e.is_retryable()))
+                .map(|(index, _)| index)
+                .collect();
+            if failed.is_empty() {
+                break;
             }
+
+            let retried =
+                Self::search_each(failed.iter().map(|&index| candidates[index]), &params).await;
+            for (index, result) in failed.into_iter().zip(retried) {
+                grouped[index] = result;
+            }
+        }
+
+        grouped
+    }
+
+    /// Iterates over the enabled sources, in registration order.
+    fn enabled_sources(&self) -> impl Iterator<Item = &dyn Source> {
+        self.sources
+            .iter()
+            .zip(&self.enabled)
+            .filter(|(_, enabled)| **enabled)
+            .map(|(source, _)| source.as_ref())
+    }
+
+    /// Iterates over the enabled sources that support every capability in `required`.
+    pub(crate) fn capable_sources<'a>(
+        &'a self,
+        required: &[Capability],
+    ) -> impl Iterator<Item = &'a dyn Source> {
+        self.enabled_sources().filter(|source| {
+            let capabilities = source.capabilities();
+            required.iter().all(|cap| capabilities.contains(cap))
+        })
+    }
+
+    /// Like [`search_all_grouped()`](Sources::search_all_grouped), but only
+    /// queries sources that support every capability in `required`.
+    pub(crate) async fn search_capable_grouped(
+        &self,
+        params: &SearchParams,
+        required: &[Capability],
+    ) -> Vec<(String, Result<Vec<Manga>>)> {
+        Self::search_each(self.capable_sources(required), params).await
+    }
+
+    /// Searches the given sources concurrently, in order.
+    async fn search_each<'a>(
+        sources: impl Iterator<Item = &'a dyn Source>,
+        params: &SearchParams,
+    ) -> Vec<(String, Result<Vec<Manga>>)> {
+        let futures = sources.map(|source| {
+            let params = params.clone();
+            async move { (source.id().to_string(), search_source(source, params).await) }
         });
 
         future::join_all(futures).await
@@ -661,7 +1449,55 @@ impl Sources {
     /// ```
     pub async fn search_all_flat(&self, params: SearchParams) -> Result<Vec<Manga>> {
         let grouped = self.search_all_grouped(params).await;
+        Self::merge_grouped(grouped)
+    }
+
+    /// Like [`search_all_grouped()`](Sources::search_all_grouped), but borrows
+    /// the parameters so they can be reused, e.g. when polling with the same
+    /// query.
+    ///
+    /// # Parameters
+    ///
+    /// * `params` - Search parameters to use for all sources
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::prelude::*;
+    ///
+    /// # async fn example() {
+    /// let sources = Sources::new();
+    /// let params = SearchParams::from("one piece");
+    ///
+    /// let first = sources.search_all_grouped_ref(&params).await;
+    /// let second = sources.search_all_grouped_ref(&params).await;
+    /// # }
+    /// ```
+    pub async fn search_all_grouped_ref(
+        &self,
+        params: &SearchParams,
+    ) -> Vec<(String, Result<Vec<Manga>>)> {
+        self.search_capable_grouped(params, &[]).await
+    }
+
+    /// Like [`search_all_flat()`](Sources::search_all_flat), but borrows the
+    /// parameters so they can be reused.
+    ///
+    /// # Parameters
+    ///
+    /// * `params` - Search parameters to use for all sources
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if all sources fail, like
+    /// [`search_all_flat()`](Sources::search_all_flat).
+    pub async fn search_all_flat_ref(&self, params: &SearchParams) -> Result<Vec<Manga>> {
+        let grouped = self.search_all_grouped_ref(params).await;
+        Self::merge_grouped(grouped)
+    }
 
+    /// Combines grouped search results, failing only if every source failed.
+    pub(crate) fn merge_grouped(grouped: Vec<(String, Result<Vec<Manga>>)>) -> Result<Vec<Manga>> {
         let mut all_results = Vec::new();
         let mut errors = Vec::new();
 
@@ -683,6 +1519,341 @@ impl Sources {
         Ok(all_results)
     }
 
+    /// Fetches the latest updates from all sources concurrently.
+    ///
+    /// Each source's [`latest_updates()`](Source::latest_updates) keeps its own
+    /// ordering, so results are grouped per source rather than merged.
+    ///
+    /// # Parameters
+    ///
+    /// * `limit` - Maximum number of manga to request from each source
+    ///
+    /// # Returns
+    ///
+    /// A vector of tuples containing the source ID and its result, in
+    /// registration order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::prelude::*;
+    ///
+    /// # async fn example() {
+    /// let sources = Sources::new();
+    ///
+    /// for (source_id, result) in sources.latest_updates_all(10).await {
+    ///     match result {
+    ///         Ok(manga) => println!("{}: {} recently updated", source_id, manga.len()),
+    ///         Err(e) => println!("{}: Error - {}", source_id, e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn latest_updates_all(&self, limit: usize) -> Vec<(String, Result<Vec<Manga>>)> {
+        let futures = self.enabled_sources().map(|source| async move {
+            let source_id = source.id().to_string();
+            (source_id, source.latest_updates(limit).await)
+        });
+
+        future::join_all(futures).await
+    }
+
+    /// Fetches chapter lists for many manga from one source with bounded concurrency.
+    ///
+    /// Up to `concurrency` [`get_chapters()`](Source::get_chapters) calls are kept in
+    /// flight at once. Requests still go through the source's own HTTP client, so
+    /// its rate limiter continues to apply. Results are returned in completion
+    /// order, each paired with the manga ID it belongs to.
+    ///
+    /// # Parameters
+    ///
+    /// * `source_id` - The ID of the source to query
+    /// * `manga_ids` - The manga to fetch chapters for
+    /// * `concurrency` - Maximum number of concurrent requests (values below 1 are treated as 1)
+    ///
+    /// # Returns
+    ///
+    /// A vector of tuples containing the manga ID and the chapter result for it.
+    /// If the source doesn't exist, every entry is an
+    /// [`Error::NotFound`](crate::Error::NotFound).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::prelude::*;
+    ///
+    /// # async fn example() {
+    /// let sources = Sources::new();
+    ///
+    /// let results = sources
+    ///     .get_chapters_many("mgd", &["manga-1", "manga-2"], 4)
+    ///     .await;
+    /// for (manga_id, result) in results {
+    ///     match result {
+    ///         Ok(chapters) => println!("{}: {} chapters", manga_id, chapters.len()),
+    ///         Err(e) => println!("{}: Error - {}", manga_id, e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_chapters_many(
+        &self,
+        source_id: &str,
+        manga_ids: &[&str],
+        concurrency: usize,
+    ) -> Vec<(String, Result<Vec<Chapter>>)> {
+        let Some(source) = self.get(source_id) else {
+            return manga_ids
+                .iter()
+                .map(|id| {
+                    let error = crate::Error::not_found(format!("Source: {}", source_id));
+                    (id.to_string(), Err(error))
+                })
+                .collect();
+        };
+
+        stream::iter(manga_ids.iter().map(|&manga_id| async move {
+            (manga_id.to_string(), source.get_chapters(manga_id).await)
+        }))
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await
+    }
+
+    /// Checks followed manga for chapters newer than the last known one.
+    ///
+    /// For every `(source_id, manga_id, last_chapter_number)` entry the current
+    /// chapter list is fetched, with at most `concurrency` requests in flight,
+    /// and all chapters numbered above `last_chapter_number` are collected.
+    /// Entries whose source is unknown or whose chapter list fails to load are
+    /// skipped, so one unreachable source doesn't hide updates from the others.
+    ///
+    /// # Parameters
+    ///
+    /// * `known` - The followed manga and the last chapter number seen for each
+    /// * `concurrency` - Maximum number of concurrent requests (values below 1 are treated as 1)
+    ///
+    /// # Returns
+    ///
+    /// One [`ChapterUpdate`] per manga that has new chapters, in completion order.
+    /// Manga without new chapters are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::prelude::*;
+    ///
+    /// # async fn example() {
+    /// let sources = Sources::with_all_enabled();
+    ///
+    /// let follows = [("mgd", "manga-1", 42.0), ("kmg", "manga-2", 7.5)];
+    /// for update in sources.check_updates(&follows, 4).await {
+    ///     println!(
+    ///         "{} new chapter(s) for {} on {}",
+    ///         update.new_chapters.len(),
+    ///         update.manga_id,
+    ///         update.source_id
+    ///     );
+    /// }
+    /// # }
+    /// ```
+    pub async fn check_updates(
+        &self,
+        known: &[(&str, &str, f64)],
+        concurrency: usize,
+    ) -> Vec<ChapterUpdate> {
+        stream::iter(
+            known
+                .iter()
+                .map(|&(source_id, manga_id, last_known)| async move {
+                    let source = self.get(source_id)?;
+                    let chapters = source.get_chapters(manga_id).await.ok()?;
+
+                    let mut new_chapters: Vec<Chapter> = chapters
+                        .into_iter()
+                        .filter(|chapter| chapter.number > last_known)
+                        .collect();
+                    if new_chapters.is_empty() {
+                        return None;
+                    }
+                    new_chapters.sort_by(Chapter::cmp_by_number);
+
+                    Some(ChapterUpdate {
+                        source_id: source_id.to_string(),
+                        manga_id: manga_id.to_string(),
+                        last_known,
+                        new_chapters,
+                    })
+                }),
+        )
+        .buffer_unordered(concurrency.max(1))
+        .filter_map(future::ready)
+        .collect()
+        .await
+    }
+
+    /// Downloads the cover images of the given manga to a local directory.
+    ///
+    /// Covers are fetched concurrently, with at most `concurrency` downloads in
+    /// flight. Each cover is saved as `{source_id}-{manga_id}.{ext}` in `dir`;
+    /// manga without a `cover_url` are skipped, and covers that already exist on
+    /// disk are not downloaded again. When `rewrite_urls` is `true`, the
+    /// `cover_url` of every manga with a local cover is replaced by the local
+    /// file path, which is handy for UIs that render thumbnails from disk.
+    ///
+    /// Each cover is requested through the [`http_client()`](Source::http_client)
+    /// of the source named by its manga's `source_id`, so the source's headers,
+    /// rate limit and retries apply. Covers of sources that aren't in the
+    /// collection, or don't expose a client, are fetched with a plain client.
+    ///
+    /// # Parameters
+    ///
+    /// * `manga` - The manga whose covers should be fetched
+    /// * `dir` - Directory to store the covers in (created if missing)
+    /// * `concurrency` - Maximum number of concurrent downloads (values below 1 are treated as 1)
+    /// * `rewrite_urls` - Whether to point `cover_url` at the local file afterwards
+    ///
+    /// # Returns
+    ///
+    /// A vector of tuples containing the manga ID and either the local cover path
+    /// or the download error. Manga without a cover URL are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use tosho::prelude::*;
+    /// use std::path::Path;
+    ///
+    /// # async fn example() -> tosho::Result<()> {
+    /// let sources = Sources::new();
+    /// let mut results = sources.search("one piece").flatten().await?;
+    ///
+    /// sources
+    ///     .prefetch_covers(&mut results, Path::new("./covers"), 8, true)
+    ///     .await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn prefetch_covers(
+        &self,
+        manga: &mut [Manga],
+        dir: &Path,
+        concurrency: usize,
+        rewrite_urls: bool,
+    ) -> Vec<(String, Result<PathBuf>)> {
+        use crate::download::{extract_extension, sanitize_filename, write_file_atomic};
+
+        let fallback = HttpClient::new("covers").with_rate_limit(0);
+        let jobs: Vec<(usize, &HttpClient, String, PathBuf)> = manga
+            .iter()
+            .enumerate()
+            .filter_map(|(index, m)| {
+                let url = m.cover_url.clone()?;
+                let client = self
+                    .get(&m.source_id)
+                    .and_then(|source| source.http_client())
+                    .unwrap_or(&fallback);
+                let extension = extract_extension(&url).unwrap_or_else(|| "jpg".to_string());
+                let filename =
+                    sanitize_filename(&format!("{}-{}.{}", m.source_id, m.id, extension));
+                Some((index, client, url, dir.join(filename)))
+            })
+            .collect();
+
+        let results: Vec<(usize, Result<PathBuf>)> = stream::iter(jobs)
+            .map(|(index, client, url, path)| async move {
+                if tokio::fs::try_exists(&path).await.unwrap_or(false) {
+                    return (index, Ok(path));
+                }
+                let result = async {
+                    let bytes = client.get(&url).await?;
+                    tokio::fs::create_dir_all(dir).await?;
+                    write_file_atomic(&path, &bytes).await?;
+                    Ok(path)
+                }
+                .await;
+                (index, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+
+        results
+            .into_iter()
+            .map(|(index, result)| {
+                if rewrite_urls && let Ok(path) = &result {
+                    manga[index].cover_url = Some(path.to_string_lossy().into_owned());
+                }
+                (manga[index].id.clone(), result)
+            })
+            .collect()
+    }
+
+    /// Returns the HTTP request counters of every source that exposes them.
+    ///
+    /// Sources that don't report statistics (see [`Source::http_stats()`]) are
+    /// left out of the result.
+    ///
+    /// # Returns
+    ///
+    /// A map from source ID to a snapshot of that source's counters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::prelude::*;
+    ///
+    /// let sources = Sources::with_all_enabled();
+    /// for (source_id, stats) in sources.stats() {
+    ///     println!("{}: {} requests, {} errors", source_id, stats.requests, stats.errors);
+    /// }
+    /// ```
+    pub fn stats(&self) -> HashMap<String, HttpStats> {
+        self.sources
+            .iter()
+            .filter_map(|source| {
+                source
+                    .http_stats()
+                    .map(|stats| (source.id().to_string(), stats))
+            })
+            .collect()
+    }
+
+    /// Changes the delay between requests of one source.
+    ///
+    /// Lets callers back off further from a source that keeps rate limiting
+    /// them, or speed up one that allows it. The new delay applies to the
+    /// source's next request.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The ID of the source to change
+    /// * `delay_ms` - Minimum delay between requests in milliseconds
+    ///
+    /// # Errors
+    ///
+    /// * [`Error::NotFound`](crate::Error::NotFound) - If no source has the given ID
+    /// * [`Error::Source`](crate::Error::Source) - If the source's rate limit can't be changed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::prelude::*;
+    /// # #[cfg(feature = "source-mangadex")]
+    /// # {
+    /// use tosho::sources::MangaDexSource;
+    ///
+    /// let mut sources = Sources::new();
+    /// sources.add(MangaDexSource::new());
+    ///
+    /// sources.set_rate_limit("mgd", 3000).unwrap();
+    /// assert_eq!(sources.get("mgd").unwrap().rate_limit_ms(), Some(3000));
+    /// # }
+    /// ```
+    pub fn set_rate_limit(&self, id: &str, delay_ms: u64) -> Result<()> {
+        self.get_or_err(id)?.set_rate_limit_ms(delay_ms)
+    }
+
     /// Returns the number of sources in the collection.
     ///
     /// # Examples
@@ -723,3 +1894,31 @@ impl Default for Sources {
         Self::new()
     }
 }
+
+/// Collects boxed sources into a [`Sources`] collection.
+///
+/// If several sources share an ID, the first one is kept and later ones are
+/// dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::prelude::*;
+///
+/// let sources: Sources = ["mgd", "kmg", "mgd"]
+///     .into_iter()
+///     .filter_map(tosho::sources::build_by_id)
+///     .collect();
+/// assert_eq!(sources.list_ids(), ["mgd", "kmg"]);
+/// ```
+impl FromIterator<Box<dyn Source>> for Sources {
+    fn from_iter<I: IntoIterator<Item = Box<dyn Source>>>(iter: I) -> Self {
+        let mut sources = Self::new();
+        for source in iter {
+            if sources.get(source.id()).is_none() {
+                sources.add_boxed(source);
+            }
+        }
+        sources
+    }
+}
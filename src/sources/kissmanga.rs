@@ -1,7 +1,8 @@
 use crate::{
     error::Result,
+    net::{HttpClient, HttpStats},
     source::Source,
-    types::{Chapter, Manga, SearchParams},
+    types::{Chapter, Manga, PageInfo, SearchParams},
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -86,6 +87,33 @@ impl KissMangaSource {
             inner: ConfigurableMadaraSource::new(config),
         }
     }
+
+    /// Parses the manga entries of a KissManga search results page
+    ///
+    /// See [`ConfigurableMadaraSource::parse_manga_list`].
+    pub fn parse_manga_list(&self, html: &str) -> Vec<Manga> {
+        self.inner.parse_manga_list(html)
+    }
+
+    /// Parses the chapter list of a KissManga manga page
+    ///
+    /// See [`ConfigurableMadaraSource::parse_chapter_list`].
+    pub fn parse_chapter_list(&self, html: &str, manga_id: &str) -> Vec<Chapter> {
+        self.inner.parse_chapter_list(html, manga_id)
+    }
+
+    /// Parses the page image URLs of a KissManga chapter page
+    ///
+    /// See [`ConfigurableMadaraSource::parse_pages`].
+    pub fn parse_pages(&self, html: &str) -> Vec<String> {
+        self.inner.parse_pages(html)
+    }
+}
+
+impl Default for KissMangaSource {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // Delegate all Source trait methods to the inner ConfigurableMadaraSource
@@ -103,6 +131,10 @@ impl Source for KissMangaSource {
         self.inner.base_url()
     }
 
+    fn min_query_length(&self) -> usize {
+        self.inner.min_query_length()
+    }
+
     async fn search(&self, params: SearchParams) -> Result<Vec<Manga>> {
         self.inner.search(params).await
     }
@@ -114,4 +146,24 @@ impl Source for KissMangaSource {
     async fn get_pages(&self, chapter_id: &str) -> Result<Vec<String>> {
         self.inner.get_pages(chapter_id).await
     }
+
+    async fn get_pages_detailed(&self, chapter_id: &str) -> Result<Vec<PageInfo>> {
+        self.inner.get_pages_detailed(chapter_id).await
+    }
+
+    fn http_client(&self) -> Option<&HttpClient> {
+        self.inner.http_client()
+    }
+
+    fn http_stats(&self) -> Option<HttpStats> {
+        self.inner.http_stats()
+    }
+
+    fn rate_limit_ms(&self) -> Option<u64> {
+        self.inner.rate_limit_ms()
+    }
+
+    fn set_rate_limit_ms(&self, delay_ms: u64) -> Result<()> {
+        self.inner.set_rate_limit_ms(delay_ms)
+    }
 }
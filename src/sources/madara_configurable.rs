@@ -1,8 +1,8 @@
 use crate::{
     error::Result,
-    net::{self, HttpClient},
+    net::{self, HttpClient, HttpStats},
     source::Source,
-    types::{Chapter, Manga, SearchParams},
+    types::{Chapter, Manga, PageInfo, SearchParams, parse_chapter_number_from_title},
 };
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -25,6 +25,9 @@ pub struct MadaraConfig {
     pub selectors: MadaraSelectors,
 }
 
+/// Madara's search returns the whole catalog for very short queries
+const MIN_QUERY_LENGTH: usize = 3;
+
 pub struct ConfigurableMadaraSource {
     config: MadaraConfig,
     client: HttpClient,
@@ -32,11 +35,17 @@ pub struct ConfigurableMadaraSource {
 
 impl ConfigurableMadaraSource {
     pub fn new(config: MadaraConfig) -> Self {
+        // Most Madara sites, and their image hosts, reject requests that don't
+        // appear to come from the site itself, so default to its own Referer
+        // and Origin. Page downloads reuse this client.
+        let origin = config.base_url.trim_end_matches('/');
         let mut client_builder = HttpClient::new(config.id)
             .with_rate_limit(2000)
-            .with_max_retries(3);
+            .with_max_retries(3)
+            .with_header("Referer", &format!("{}/", origin))
+            .with_header("Origin", origin);
 
-        // Apply custom headers if provided
+        // Apply custom headers if provided, replacing the defaults above
         if let Some(headers) = &config.headers {
             for (key, value) in headers {
                 client_builder = client_builder.with_header(key, value);
@@ -65,32 +74,22 @@ impl ConfigurableMadaraSource {
         let trimmed_path = path.trim_start_matches('/');
         format!("{}/{}", trimmed_base, trimmed_path)
     }
-}
-
-#[async_trait]
-impl Source for ConfigurableMadaraSource {
-    fn id(&self) -> &'static str {
-        &self.config.id
-    }
 
-    fn name(&self) -> &'static str {
-        &self.config.name
-    }
-
-    fn base_url(&self) -> &str {
-        &self.config.base_url
+    // Helper function to resolve the reader page URL of a chapter
+    fn chapter_url(&self, chapter_id: &str) -> String {
+        if chapter_id.starts_with("http") {
+            chapter_id.to_string()
+        } else {
+            self.full_url(chapter_id)
+        }
     }
 
-    async fn search(&self, params: SearchParams) -> Result<Vec<Manga>> {
-        let url = format!(
-            "{}/?s={}&post_type=wp-manga",
-            self.config.base_url,
-            urlencoding::encode(&params.query)
-        );
-
-        let html_str = self.client.get_text(&url).await?;
-
-        let html = net::html::parse(&html_str);
+    /// Parses the manga entries of a search results page
+    ///
+    /// Kept separate from [`search()`](Source::search) so the scraping can be
+    /// tested against captured pages without network access.
+    pub fn parse_manga_list(&self, html: &str) -> Vec<Manga> {
+        let html = net::html::parse(html);
         let links = net::html::select_all_attr(&html, &self.config.selectors.manga_item, "href");
         let titles = net::html::select_all_text(&html, &self.config.selectors.manga_item);
         let cover_images =
@@ -109,8 +108,8 @@ impl Source for ConfigurableMadaraSource {
 
             // The unique ID is the relative path to the manga, without leading/trailing slashes.
             // If href contains the base URL, extract only the relative part
-            let id = if href.contains(&self.config.base_url) {
-                href.replace(&self.config.base_url, "")
+            let id = if href.contains(self.config.base_url) {
+                href.replace(self.config.base_url, "")
                     .trim_matches('/')
                     .to_string()
             } else {
@@ -135,80 +134,88 @@ impl Source for ConfigurableMadaraSource {
                 authors: vec![],
                 description: None,
                 tags: vec![],
+                content_rating: None,
+                available_languages: vec![],
                 source_id: self.id().to_string(),
             });
         }
 
-        // Apply limit if specified
-        let manga = if let Some(limit) = params.limit {
-            manga.into_iter().take(limit).collect()
-        } else {
-            manga
-        };
-
-        Ok(manga)
+        manga
     }
 
-    async fn get_chapters(&self, manga_id: &str) -> Result<Vec<Chapter>> {
-        let url = if manga_id.starts_with("http") {
-            manga_id.to_string()
-        } else {
-            self.full_url(manga_id)
-        };
-
-        let html_str = self.client.get_text(&url).await?;
-        let html = net::html::parse(&html_str);
+    /// Parses the chapter list of a manga page
+    ///
+    /// Chapters are numbered from their titles. Entries without a number in
+    /// their title, such as extras or oneshots, get number 0. Only if no title
+    /// in the list has a number are chapters numbered by their position
+    /// instead, counting from the oldest (last listed) chapter.
+    ///
+    /// The chapters are returned in reading order, sorted with
+    /// [`Chapter::cmp_by_number`]. Chapters with equal numbers stay oldest first.
+    pub fn parse_chapter_list(&self, html: &str, manga_id: &str) -> Vec<Chapter> {
+        let html = net::html::parse(html);
 
         // Try to get chapter links and titles
         let links = net::html::select_all_attr(&html, &self.config.selectors.chapter_links, "href");
         let titles = net::html::select_all_text(&html, &self.config.selectors.chapter_titles);
 
-        let chapters: Vec<Chapter> = links
+        let entries: Vec<(String, String)> = links
             .into_iter()
             .zip(titles)
-            .enumerate()
-            .filter_map(|(i, (href, title))| {
-                if href.trim().is_empty() {
-                    return None;
-                }
+            .filter(|(href, _)| !href.trim().is_empty())
+            .collect();
+        let numbers: Vec<Option<f64>> = entries
+            .iter()
+            .map(|(_, title)| parse_chapter_number_from_title(title))
+            .collect();
+        let any_numbered = numbers.iter().any(Option::is_some);
+        let count = entries.len();
 
+        let mut chapters: Vec<Chapter> = entries
+            .into_iter()
+            .zip(numbers)
+            .enumerate()
+            .map(|(i, ((href, title), number))| {
                 // Extract chapter ID from URL
                 let id = href.trim_matches('/').to_string();
 
-                Some(Chapter {
+                // Madara lists chapters newest first
+                let number = match number {
+                    Some(number) => number,
+                    None if any_numbered => 0.0,
+                    None => (count - i) as f64,
+                };
+
+                Chapter {
                     id,
-                    number: (i + 1) as f64,
+                    number,
                     title: title.trim().to_string(),
                     volume: None,
                     pages: vec![],
+                    page_count: None,
                     manga_id: manga_id.to_string(),
                     source_id: self.id().to_string(),
-                })
+                }
             })
             .collect();
 
-        Ok(chapters)
+        chapters.reverse();
+        chapters.sort_by(Chapter::cmp_by_number);
+        chapters
     }
 
-    async fn get_pages(&self, chapter_id: &str) -> Result<Vec<String>> {
-        let url = if chapter_id.starts_with("http") {
-            chapter_id.to_string()
-        } else {
-            self.full_url(chapter_id)
-        };
-
-        let html_str = self.client.get_text(&url).await?;
-        let html = net::html::parse(&html_str);
+    /// Parses the page image URLs of a chapter reader page
+    ///
+    /// Images that look like ads, icons or loading placeholders are left out,
+    /// so the result may be empty even if the page has images.
+    pub fn parse_pages(&self, html: &str) -> Vec<String> {
+        let html = net::html::parse(html);
 
         // Try to get page images
         let pages = net::html::select_all_attr(&html, &self.config.selectors.chapter_pages, "src");
 
-        if pages.is_empty() {
-            return Err(crate::Error::not_found("No pages found"));
-        }
-
         // Filter out small images (likely ads or icons)
-        let pages: Vec<String> = pages
+        pages
             .into_iter()
             .filter(|url| {
                 // Filter out tiny images and common ad patterns
@@ -221,14 +228,108 @@ impl Source for ConfigurableMadaraSource {
                 // Make sure it's a valid image URL
                 (url.contains(".jpg") || url.contains(".png") || url.contains(".jpeg") || url.contains(".webp"))
             })
-            .collect();
+            .collect()
+    }
+}
+
+#[async_trait]
+impl Source for ConfigurableMadaraSource {
+    fn id(&self) -> &'static str {
+        self.config.id
+    }
+
+    fn name(&self) -> &'static str {
+        self.config.name
+    }
+
+    fn base_url(&self) -> &str {
+        self.config.base_url
+    }
+
+    fn min_query_length(&self) -> usize {
+        MIN_QUERY_LENGTH
+    }
+
+    async fn search(&self, params: SearchParams) -> Result<Vec<Manga>> {
+        if params.query.trim().chars().count() < self.min_query_length() {
+            return Err(crate::Error::parse(format!(
+                "Query too short (at least {} characters required)",
+                self.min_query_length()
+            )));
+        }
+
+        let url = format!(
+            "{}/?s={}&post_type=wp-manga",
+            self.config.base_url,
+            urlencoding::encode(&params.query)
+        );
+
+        let html = self.client.get_text(&url).await?;
+        let manga = self.parse_manga_list(&html);
+
+        // Apply limit if specified
+        let manga = if let Some(limit) = params.limit {
+            manga.into_iter().take(limit).collect()
+        } else {
+            manga
+        };
+
+        Ok(manga)
+    }
+
+    async fn get_chapters(&self, manga_id: &str) -> Result<Vec<Chapter>> {
+        let url = if manga_id.starts_with("http") {
+            manga_id.to_string()
+        } else {
+            self.full_url(manga_id)
+        };
+
+        let html = self.client.get_text(&url).await?;
+        Ok(self.parse_chapter_list(&html, manga_id))
+    }
+
+    async fn get_pages(&self, chapter_id: &str) -> Result<Vec<String>> {
+        let url = self.chapter_url(chapter_id);
+
+        let html = self.client.get_text(&url).await?;
+        let pages = self.parse_pages(&html);
 
         if pages.is_empty() {
-            return Err(crate::Error::not_found(
-                "No valid pages found after filtering",
-            ));
+            return Err(crate::Error::not_found("No pages found"));
         }
 
         Ok(pages)
     }
+
+    async fn get_pages_detailed(&self, chapter_id: &str) -> Result<Vec<PageInfo>> {
+        // Madara image hosts commonly reject hotlinks, so images are
+        // requested as if loaded from the chapter's reader page
+        let referer = self.chapter_url(chapter_id);
+        let pages = self.get_pages(chapter_id).await?;
+
+        Ok(pages
+            .into_iter()
+            .map(|url| PageInfo {
+                referer: Some(referer.clone()),
+                ..PageInfo::from(url)
+            })
+            .collect())
+    }
+
+    fn http_client(&self) -> Option<&HttpClient> {
+        Some(&self.client)
+    }
+
+    fn http_stats(&self) -> Option<HttpStats> {
+        Some(self.client.stats())
+    }
+
+    fn rate_limit_ms(&self) -> Option<u64> {
+        Some(self.client.rate_limit_ms())
+    }
+
+    fn set_rate_limit_ms(&self, delay_ms: u64) -> Result<()> {
+        self.client.set_rate_limit(delay_ms);
+        Ok(())
+    }
 }
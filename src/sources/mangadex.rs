@@ -1,18 +1,23 @@
 use crate::{
-    error::Result,
-    net::HttpClient,
-    source::Source,
-    types::{Chapter, Manga, SearchParams, SortOrder},
+    error::{Error, Result},
+    net::{self, HttpClient, HttpStats},
+    source::{Capability, Source},
+    types::{Chapter, Manga, SearchParams, SortOrder, parse_chapter_number_from_title},
 };
 use async_trait::async_trait;
-use serde::Deserialize;
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, de::DeserializeOwned};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 /// MangaDex API search response
+///
+/// Entries are kept as raw JSON so each one can be parsed on its own; see
+/// [`MangaDexSource::map_search_response`].
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
 struct MangaDexSearchResponse {
-    data: Vec<MangaDexMangaData>,
+    data: Vec<serde_json::Value>,
     total: u32,
     limit: u32,
     offset: u32,
@@ -37,25 +42,43 @@ struct MangaDexMangaData {
 }
 
 /// MangaDex manga attributes
+///
+/// Niche titles sometimes omit fields, so everything that can be missing
+/// defaults to empty rather than failing the whole response.
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
 struct MangaDexMangaAttributes {
+    #[serde(default)]
     title: HashMap<String, String>,
-    #[serde(rename = "altTitles")]
+    #[serde(rename = "altTitles", default)]
     alt_titles: Vec<HashMap<String, String>>,
+    #[serde(default)]
     description: HashMap<String, String>,
     status: String,
+    #[serde(default)]
     tags: Vec<MangaDexTag>,
     #[serde(rename = "updatedAt")]
     updated_at: Option<String>,
+    #[serde(rename = "contentRating")]
+    content_rating: Option<String>,
+    #[serde(rename = "availableTranslatedLanguages", default)]
+    available_translated_languages: Vec<Option<String>>,
 }
 
 /// MangaDex tag structure
 #[derive(Debug, Deserialize)]
 struct MangaDexTag {
+    #[serde(default)]
+    id: String,
     attributes: MangaDexTagAttributes,
 }
 
+/// MangaDex tag list response (`/manga/tag`)
+#[derive(Debug, Deserialize)]
+struct MangaDexTagListResponse {
+    data: Vec<MangaDexTag>,
+}
+
 /// MangaDex tag attributes
 #[derive(Debug, Deserialize)]
 struct MangaDexTagAttributes {
@@ -115,6 +138,8 @@ struct MangaDexChapterAttributes {
     publish_at: Option<String>,
     #[serde(rename = "translatedLanguage")]
     translated_language: String,
+    #[serde(rename = "pages", default)]
+    pages_count: Option<u32>,
 }
 
 /// MangaDex pages response (at-home server)
@@ -134,6 +159,27 @@ struct MangaDexChapterPages {
     data_saver: Vec<String>,
 }
 
+/// MangaDex API error envelope
+///
+/// MangaDex reports failures as `{"result": "error", "errors": [...]}`,
+/// sometimes with a 200 status, so the body has to be checked as well.
+#[derive(Debug, Deserialize)]
+struct MangaDexErrorResponse {
+    #[serde(default)]
+    errors: Vec<MangaDexApiError>,
+}
+
+/// A single entry of a MangaDex error envelope
+#[derive(Debug, Deserialize)]
+struct MangaDexApiError {
+    status: Option<u16>,
+    title: Option<String>,
+    detail: Option<String>,
+}
+
+/// Maximum number of IDs the `/manga` endpoint accepts in one request
+pub const MAX_BATCH_IDS: usize = 100;
+
 /// MangaDex source implementation for accessing manga from MangaDex.org.
 ///
 /// This source provides access to the MangaDex API, supporting search functionality,
@@ -155,6 +201,9 @@ struct MangaDexChapterPages {
 /// a 1-second delay between requests. The API allows up to 5 requests
 /// per second, but we use a conservative limit to avoid issues.
 ///
+/// Use [`MangaDexSource::builder()`] to change these defaults, e.g. to point the
+/// source at a mirror or a self-hosted proxy.
+///
 /// # Examples
 ///
 /// ```rust
@@ -181,18 +230,132 @@ struct MangaDexChapterPages {
 pub struct MangaDexSource {
     client: HttpClient,
     api_base: String,
+    preferred_languages: Vec<String>,
+    cover_size: CoverSize,
+    skipped_entries: AtomicUsize,
+    /// Lowercased tag names mapped to their IDs, fetched on first use
+    tag_ids: OnceCell<HashMap<String, String>>,
 }
 
-impl MangaDexSource {
-    /// Create a new MangaDex source
-    pub fn new() -> Self {
+/// Size of the cover images linked in [`Manga::cover_url`].
+///
+/// MangaDex serves every cover in its original resolution and as 512px and
+/// 256px wide thumbnails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoverSize {
+    /// The cover as uploaded (default)
+    #[default]
+    Original,
+    /// 512px wide thumbnail
+    Medium,
+    /// 256px wide thumbnail
+    Small,
+}
+
+/// Builder for a [`MangaDexSource`] with non-default settings.
+///
+/// Created by [`MangaDexSource::builder()`]. Every setting is optional and
+/// falls back to the value [`MangaDexSource::new()`] uses.
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::sources::MangaDexSource;
+/// use tosho::sources::mangadex::CoverSize;
+///
+/// let source = MangaDexSource::builder()
+///     .api_base("https://mangadex-proxy.example.com")
+///     .rate_limit_ms(250)
+///     .max_retries(5)
+///     .preferred_languages(vec!["en".to_string(), "es-la".to_string()])
+///     .cover_size(CoverSize::Medium)
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct MangaDexSourceBuilder {
+    api_base: String,
+    rate_limit_ms: u64,
+    max_retries: u32,
+    preferred_languages: Vec<String>,
+    cover_size: CoverSize,
+}
+
+impl Default for MangaDexSourceBuilder {
+    fn default() -> Self {
         Self {
-            client: HttpClient::new("mangadex")
-                .with_rate_limit(1000) // 1 second between requests (5 req/sec limit)
-                .with_max_retries(3),
             api_base: "https://api.mangadex.org".to_string(),
+            rate_limit_ms: 1000, // 1 second between requests (5 req/sec limit)
+            max_retries: 3,
+            preferred_languages: vec!["en".to_string()],
+            cover_size: CoverSize::Original,
         }
     }
+}
+
+impl MangaDexSourceBuilder {
+    /// Sets the API base URL, e.g. a mirror or proxy (default `https://api.mangadex.org`)
+    pub fn api_base(mut self, api_base: impl Into<String>) -> Self {
+        self.api_base = api_base.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the delay between requests in milliseconds (default 1000)
+    pub fn rate_limit_ms(mut self, rate_limit_ms: u64) -> Self {
+        self.rate_limit_ms = rate_limit_ms;
+        self
+    }
+
+    /// Sets how often failed requests are retried (default 3)
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the translation languages chapter lists are fetched in (default `["en"]`)
+    pub fn preferred_languages(mut self, languages: Vec<String>) -> Self {
+        self.preferred_languages = languages;
+        self
+    }
+
+    /// Sets the size of the linked cover images (default [`CoverSize::Original`])
+    pub fn cover_size(mut self, cover_size: CoverSize) -> Self {
+        self.cover_size = cover_size;
+        self
+    }
+
+    /// Builds the source
+    pub fn build(self) -> MangaDexSource {
+        MangaDexSource {
+            client: HttpClient::new("mangadex")
+                .with_rate_limit(self.rate_limit_ms)
+                .with_max_retries(self.max_retries),
+            api_base: self.api_base,
+            preferred_languages: self.preferred_languages,
+            cover_size: self.cover_size,
+            skipped_entries: AtomicUsize::new(0),
+            tag_ids: OnceCell::new(),
+        }
+    }
+}
+
+impl MangaDexSource {
+    /// Create a new MangaDex source
+    pub fn new() -> Self {
+        Self::builder().build()
+    }
+
+    /// Create a builder for a MangaDex source with custom settings
+    pub fn builder() -> MangaDexSourceBuilder {
+        MangaDexSourceBuilder::default()
+    }
+
+    /// Number of manga entries skipped so far because they couldn't be parsed
+    ///
+    /// A malformed entry is left out of the results instead of failing the
+    /// whole search; this counter makes those omissions visible.
+    pub fn skipped_entries(&self) -> usize {
+        self.skipped_entries.load(Ordering::Relaxed)
+    }
 
     /// Extract the best title from a multi-language title map
     fn extract_best_title(title_map: &HashMap<String, String>) -> String {
@@ -200,10 +363,10 @@ impl MangaDexSource {
         let priority_langs = ["en", "en-us", "ja", "ja-ro"];
 
         for lang in &priority_langs {
-            if let Some(title) = title_map.get(*lang) {
-                if !title.trim().is_empty() {
-                    return title.trim().to_string();
-                }
+            if let Some(title) = title_map.get(*lang)
+                && !title.trim().is_empty()
+            {
+                return title.trim().to_string();
             }
         }
 
@@ -216,62 +379,252 @@ impl MangaDexSource {
     }
 
     /// Format search query parameters
+    ///
+    /// An empty query omits `title` entirely, which makes MangaDex return its
+    /// browse listing. Without a query relevance is meaningless, so browsing
+    /// defaults to sorting by popularity.
     fn format_search_query(&self, query: &str, params: &SearchParams) -> String {
-        let mut query_parts = vec![
-            format!("title={}", urlencoding::encode(query)),
-            format!("limit={}", params.limit.unwrap_or(20)),
-            "includes[]=cover_art".to_string(),
-        ];
+        let browsing = query.trim().is_empty();
+        let mut pairs = Vec::new();
+        if !browsing {
+            pairs.push(("title", query.to_string()));
+        }
+        pairs.push(("limit", params.limit.unwrap_or(20).to_string()));
+        pairs.push(("includes[]", "cover_art".to_string()));
 
         // Add order parameters
         match params.sort_by {
-            Some(SortOrder::UpdatedAt) => {
-                query_parts.push("order[updatedAt]=desc".to_string());
-            }
-            Some(SortOrder::CreatedAt) => {
-                query_parts.push("order[createdAt]=desc".to_string());
-            }
-            Some(SortOrder::Title) => {
-                query_parts.push("order[title]=asc".to_string());
-            }
-            _ => {
-                query_parts.push("order[relevance]=desc".to_string());
-            }
+            Some(SortOrder::UpdatedAt) => pairs.push(("order[updatedAt]", "desc".to_string())),
+            Some(SortOrder::CreatedAt) => pairs.push(("order[createdAt]", "desc".to_string())),
+            Some(SortOrder::Title) => pairs.push(("order[title]", "asc".to_string())),
+            Some(SortOrder::Popularity) => pairs.push(("order[followedCount]", "desc".to_string())),
+            _ if browsing => pairs.push(("order[followedCount]", "desc".to_string())),
+            _ => pairs.push(("order[relevance]", "desc".to_string())),
         }
 
         // Add content ratings
         let content_ratings = ["safe", "suggestive", "erotica", "pornographic"];
         for rating in &content_ratings {
-            query_parts.push(format!("contentRating[]={}", rating));
+            pairs.push(("contentRating[]", rating.to_string()));
         }
 
         // Add offset if specified
         if let Some(offset) = params.offset {
-            query_parts.push(format!("offset={}", offset));
+            pairs.push(("offset", offset.to_string()));
+        }
+
+        net::build_query(&pairs)
+    }
+
+    /// Format query parameters for the latest updates listing
+    fn format_latest_query(&self, limit: usize) -> String {
+        let mut pairs = vec![
+            ("limit", limit.to_string()),
+            ("includes[]", "cover_art".to_string()),
+            ("order[latestUploadedChapter]", "desc".to_string()),
+        ];
+
+        let content_ratings = ["safe", "suggestive", "erotica", "pornographic"];
+        for rating in &content_ratings {
+            pairs.push(("contentRating[]", rating.to_string()));
+        }
+
+        net::build_query(&pairs)
+    }
+
+    /// Tag name to ID lookup, fetched from `/manga/tag` once per source
+    ///
+    /// The search endpoint filters by tag ID, while [`SearchParams`] carries
+    /// tag names as they appear in [`Manga::tags`].
+    async fn tag_ids(&self) -> Result<&HashMap<String, String>> {
+        if let Some(tag_ids) = self.tag_ids.get() {
+            return Ok(tag_ids);
+        }
+
+        let url = format!("{}/manga/tag", self.api_base);
+        let response: MangaDexTagListResponse = self.get_api(&url).await?;
+        let tag_ids = response
+            .data
+            .into_iter()
+            .map(|tag| {
+                let name = Self::extract_best_title(&tag.attributes.name);
+                (name.to_lowercase(), tag.id)
+            })
+            .collect();
+
+        // A concurrent search may have filled the cell first; both lookups are equal
+        Ok(self.tag_ids.get_or_init(|| tag_ids))
+    }
+
+    /// Format `includedTags[]`/`excludedTags[]` parameters for the given tag names
+    ///
+    /// Names are matched case-insensitively. A name that isn't in `tag_ids`
+    /// is an error rather than being dropped, which would widen the search.
+    fn format_tag_query(
+        &self,
+        tag_ids: &HashMap<String, String>,
+        params: &SearchParams,
+    ) -> Result<String> {
+        let mut pairs = Vec::new();
+        for (key, names) in [
+            ("includedTags[]", &params.include_tags),
+            ("excludedTags[]", &params.exclude_tags),
+        ] {
+            for name in names {
+                let id = tag_ids
+                    .get(&name.to_lowercase())
+                    .ok_or_else(|| Error::not_found(format!("Unknown MangaDex tag: {}", name)))?;
+                pairs.push((key, id.clone()));
+            }
+        }
+
+        Ok(net::build_query(&pairs))
+    }
+
+    /// Full search query string, resolving tag names to IDs when filtering by tag
+    async fn search_query(&self, params: &SearchParams) -> Result<String> {
+        let mut query_params = self.format_search_query(&params.query, params);
+        if !params.include_tags.is_empty() || !params.exclude_tags.is_empty() {
+            let tag_ids = self.tag_ids().await?;
+            query_params.push('&');
+            query_params.push_str(&self.format_tag_query(tag_ids, params)?);
+        }
+        Ok(query_params)
+    }
+
+    /// Fetch a JSON endpoint, turning `result: "error"` bodies into errors
+    async fn get_api<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+        let value: serde_json::Value = self.client.get_json_with_retry_on_parse(url).await?;
+        self.parse_api_response(value)
+    }
+
+    /// Check the `result` field of a response before deserializing it
+    fn parse_api_response<T: DeserializeOwned>(&self, value: serde_json::Value) -> Result<T> {
+        if value.get("result").and_then(|r| r.as_str()) == Some("error") {
+            let response: MangaDexErrorResponse =
+                serde_json::from_value(value).unwrap_or(MangaDexErrorResponse { errors: vec![] });
+            let first = response.errors.into_iter().next();
+            let status = first.as_ref().and_then(|e| e.status);
+            let message = first
+                .and_then(|e| e.detail.or(e.title))
+                .unwrap_or_else(|| "API returned an error".to_string());
+
+            return Err(Error::Source {
+                src: self.id().to_string(),
+                message,
+                status,
+                cause: None,
+            });
+        }
+
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Build the `/manga` endpoint URL for the given query string
+    fn manga_list_url(&self, query_params: &str) -> String {
+        format!("{}/manga?{}", self.api_base, query_params)
+    }
+
+    /// Fetch a page of manga from the `/manga` endpoint
+    async fn fetch_manga_list(&self, query_params: &str) -> Result<Vec<Manga>> {
+        let search_url = self.manga_list_url(query_params);
+
+        let response: MangaDexSearchResponse = self.get_api(&search_url).await?;
+
+        Ok(self.map_search_response(response))
+    }
+
+    /// Fetch the metadata of many manga at once
+    ///
+    /// Refreshing a library one [`Manga`] at a time costs a rate-limited request
+    /// per entry. The `/manga` endpoint accepts up to [`MAX_BATCH_IDS`] IDs per
+    /// request, so `ids` is split into chunks of that size.
+    ///
+    /// # Parameters
+    ///
+    /// * `ids` - MangaDex manga IDs
+    ///
+    /// # Returns
+    ///
+    /// The manga MangaDex knows about, in the order the API returns them. IDs
+    /// that don't exist are left out rather than failing the batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of any chunk request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use tosho::sources::MangaDexSource;
+    /// # async fn example() -> tosho::Result<()> {
+    /// let source = MangaDexSource::new();
+    /// let manga = source
+    ///     .get_manga_batch(&["a1c7c817-4e59-43b7-9365-09675a149a6f"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_manga_batch(&self, ids: &[&str]) -> Result<Vec<Manga>> {
+        let mut manga = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(MAX_BATCH_IDS) {
+            manga.extend(
+                self.fetch_manga_list(&self.format_batch_query(chunk))
+                    .await?,
+            );
+        }
+        Ok(manga)
+    }
+
+    /// Format query parameters for looking up a chunk of manga by ID
+    fn format_batch_query(&self, ids: &[&str]) -> String {
+        let mut pairs: Vec<(&str, String)> =
+            ids.iter().map(|id| ("ids[]", id.to_string())).collect();
+        pairs.push(("limit", ids.len().to_string()));
+        pairs.push(("includes[]", "cover_art".to_string()));
+
+        // Without explicit ratings MangaDex silently drops mature titles
+        let content_ratings = ["safe", "suggestive", "erotica", "pornographic"];
+        for rating in &content_ratings {
+            pairs.push(("contentRating[]", rating.to_string()));
         }
 
-        query_parts.join("&")
+        net::build_query(&pairs)
+    }
+
+    /// Map every parseable entry of a search response, skipping broken ones
+    fn map_search_response(&self, response: MangaDexSearchResponse) -> Vec<Manga> {
+        response
+            .data
+            .into_iter()
+            .filter_map(
+                |entry| match serde_json::from_value::<MangaDexMangaData>(entry) {
+                    Ok(manga_data) => Some(self.map_manga_data_to_manga(&manga_data)),
+                    Err(_) => {
+                        self.skipped_entries.fetch_add(1, Ordering::Relaxed);
+                        None
+                    }
+                },
+            )
+            .collect()
     }
 
     /// Format chapter query parameters
     fn format_chapters_query(&self, offset: u32, limit: u32) -> String {
-        let params = vec![
+        let mut pairs = vec![
             ("limit", limit.to_string()),
             ("offset", offset.to_string()),
             ("order[volume]", "asc".to_string()),
             ("order[chapter]", "asc".to_string()),
-            ("translatedLanguage[]", "en".to_string()),
-            ("contentRating[]", "safe".to_string()),
-            ("contentRating[]", "suggestive".to_string()),
-            ("contentRating[]", "erotica".to_string()),
-            ("contentRating[]", "pornographic".to_string()),
         ];
+        for language in &self.preferred_languages {
+            pairs.push(("translatedLanguage[]", language.clone()));
+        }
+        for rating in ["safe", "suggestive", "erotica", "pornographic"] {
+            pairs.push(("contentRating[]", rating.to_string()));
+        }
 
-        params
-            .iter()
-            .map(|(key, value)| format!("{}={}", key, urlencoding::encode(value)))
-            .collect::<Vec<_>>()
-            .join("&")
+        net::build_query(&pairs)
     }
 
     /// Fetch all chapters for a manga (handles pagination)
@@ -284,7 +637,7 @@ impl MangaDexSource {
             let query_params = self.format_chapters_query(offset, LIMIT);
             let url = format!("{}/manga/{}/feed?{}", self.api_base, manga_id, query_params);
 
-            let response: MangaDexChapterListResponse = self.client.get_json(&url).await?;
+            let response: MangaDexChapterListResponse = self.get_api(&url).await?;
 
             // Map chapters
             for chapter_data in response.data {
@@ -302,11 +655,7 @@ impl MangaDexSource {
         }
 
         // Sort chapters by number
-        all_chapters.sort_by(|a, b| {
-            a.number
-                .partial_cmp(&b.number)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
+        all_chapters.sort_by(Chapter::cmp_by_number);
 
         Ok(all_chapters)
     }
@@ -317,11 +666,18 @@ impl MangaDexSource {
         data: &MangaDexChapterData,
         manga_id: &str,
     ) -> Option<Chapter> {
+        // Some uploads leave the number field empty and only name it in the title
         let chapter_num = data
             .attributes
             .chapter
             .as_ref()
             .and_then(|ch| ch.parse::<f64>().ok())
+            .or_else(|| {
+                data.attributes
+                    .title
+                    .as_deref()
+                    .and_then(parse_chapter_number_from_title)
+            })
             .unwrap_or(0.0);
 
         // Extract volume number
@@ -343,6 +699,11 @@ impl MangaDexSource {
             volume: volume_num,
             title,
             pages: vec![], // Pages are fetched separately
+            page_count: data
+                .attributes
+                .pages_count
+                .filter(|&count| count > 0)
+                .map(|count| count as usize),
             manga_id: manga_id.to_string(),
             source_id: self.id().to_string(),
         })
@@ -357,7 +718,7 @@ impl MangaDexSource {
                 rel.attributes
                     .as_ref()
                     .and_then(|attr| attr.file_name.as_ref())
-                    .map(|filename| filename.clone())
+                    .cloned()
             })
     }
 
@@ -375,7 +736,7 @@ impl MangaDexSource {
                 rel.attributes
                     .as_ref()
                     .and_then(|attr| attr.name.as_ref())
-                    .map(|name| name.clone())
+                    .cloned()
             })
             .collect();
 
@@ -389,9 +750,14 @@ impl MangaDexSource {
 
         // Try to find cover art URL from relationships using reference expansion
         let cover_url = if let Some(filename) = self.extract_cover_filename(data) {
+            let suffix = match self.cover_size {
+                CoverSize::Original => "",
+                CoverSize::Medium => ".512.jpg",
+                CoverSize::Small => ".256.jpg",
+            };
             let url = format!(
-                "https://uploads.mangadex.org/covers/{}/{}",
-                data.id, filename
+                "https://uploads.mangadex.org/covers/{}/{}{}",
+                data.id, filename, suffix
             );
             Some(url)
         } else {
@@ -410,6 +776,18 @@ impl MangaDexSource {
                 Some(description)
             },
             tags,
+            content_rating: data
+                .attributes
+                .content_rating
+                .as_deref()
+                .and_then(|rating| rating.parse().ok()),
+            available_languages: data
+                .attributes
+                .available_translated_languages
+                .iter()
+                .flatten()
+                .cloned()
+                .collect(),
             source_id: self.id().to_string(),
         }
     }
@@ -436,34 +814,85 @@ impl Source for MangaDexSource {
     }
 
     async fn search(&self, params: SearchParams) -> Result<Vec<Manga>> {
-        let query_params = self.format_search_query(&params.query, &params);
-        let search_url = format!("{}/manga?{}", self.api_base, query_params);
+        let query_params = self.search_query(&params).await?;
+        self.fetch_manga_list(&query_params).await
+    }
 
-        let response: MangaDexSearchResponse = self.client.get_json(&search_url).await?;
+    fn explain(&self, params: &SearchParams) -> Vec<(String, String)> {
+        let mut query_params = self.format_search_query(&params.query, params);
+        let mut requests = Vec::new();
+        let mut unresolved = Vec::new();
 
-        let manga_list: Vec<Manga> = response
-            .data
-            .iter()
-            .map(|manga_data| self.map_manga_data_to_manga(manga_data))
-            .collect();
+        if !params.include_tags.is_empty() || !params.exclude_tags.is_empty() {
+            // Tag IDs are only known once fetched, which the search would do first
+            let tag_ids = match self.tag_ids.get() {
+                Some(tag_ids) => Some(tag_ids),
+                None => {
+                    requests.push(("tags".to_string(), format!("{}/manga/tag", self.api_base)));
+                    None
+                }
+            };
+
+            let mut pairs = Vec::new();
+            for (key, names) in [
+                ("includedTags[]", &params.include_tags),
+                ("excludedTags[]", &params.exclude_tags),
+            ] {
+                let mut missing = Vec::new();
+                for name in names {
+                    match tag_ids.and_then(|ids| ids.get(&name.to_lowercase())) {
+                        Some(id) => pairs.push((key, id.clone())),
+                        None => missing.push(name.as_str()),
+                    }
+                }
+                if !missing.is_empty() {
+                    unresolved.push(format!("{}: {}", key, missing.join(", ")));
+                }
+            }
 
-        Ok(manga_list)
+            if !pairs.is_empty() {
+                query_params.push('&');
+                query_params.push_str(&net::build_query(&pairs));
+            }
+        }
+
+        requests.push(("search".to_string(), self.manga_list_url(&query_params)));
+        // Names without a known ID are listed rather than silently left out of the URL
+        if !unresolved.is_empty() {
+            requests.push(("unresolved tags".to_string(), unresolved.join("; ")));
+        }
+        requests
+    }
+
+    async fn latest_updates(&self, limit: usize) -> Result<Vec<Manga>> {
+        let query_params = self.format_latest_query(limit);
+        self.fetch_manga_list(&query_params).await
     }
 
     async fn get_chapters(&self, manga_id: &str) -> Result<Vec<Chapter>> {
         self.fetch_all_chapters(manga_id).await
     }
 
+    async fn get_page_count(&self, chapter_id: &str) -> Result<usize> {
+        let chapter_info_url = format!("{}/chapter/{}", self.api_base, chapter_id);
+        let chapter_info: MangaDexChapterResponse = self.get_api(&chapter_info_url).await?;
+
+        // External chapters report 0 pages; resolve those the slow way
+        match chapter_info.data.attributes.pages_count {
+            Some(count) if count > 0 => Ok(count as usize),
+            _ => Ok(self.get_pages(chapter_id).await?.len()),
+        }
+    }
+
     async fn get_pages(&self, chapter_id: &str) -> Result<Vec<String>> {
         // First, fetch chapter info to get manga ID
         let chapter_info_url = format!("{}/chapter/{}", self.api_base, chapter_id);
 
-        let _chapter_info: MangaDexChapterResponse =
-            self.client.get_json(&chapter_info_url).await?;
+        let _chapter_info: MangaDexChapterResponse = self.get_api(&chapter_info_url).await?;
 
         // Then fetch page URLs from at-home server
         let pages_url = format!("{}/at-home/server/{}", self.api_base, chapter_id);
-        let pages_response: MangaDexPagesResponse = self.client.get_json(&pages_url).await?;
+        let pages_response: MangaDexPagesResponse = self.get_api(&pages_url).await?;
 
         // Validate that we have the necessary data
         if pages_response.chapter.hash.is_empty() {
@@ -515,4 +944,320 @@ impl Source for MangaDexSource {
         }
         Ok(page_urls)
     }
+
+    fn capabilities(&self) -> &'static [Capability] {
+        &[
+            Capability::TagFiltering,
+            Capability::Sorting,
+            Capability::Pagination,
+        ]
+    }
+
+    fn page_urls_expire(&self) -> bool {
+        // at-home server URLs carry a token that is only valid for a few minutes
+        true
+    }
+
+    fn http_client(&self) -> Option<&HttpClient> {
+        Some(&self.client)
+    }
+
+    fn http_stats(&self) -> Option<HttpStats> {
+        Some(self.client.stats())
+    }
+
+    fn rate_limit_ms(&self) -> Option<u64> {
+        Some(self.client.rate_limit_ms())
+    }
+
+    fn set_rate_limit_ms(&self, delay_ms: u64) -> Result<()> {
+        self.client.set_rate_limit(delay_ms);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ContentRating;
+
+    #[test]
+    fn test_map_manga_rating_and_languages() {
+        let response: MangaDexSearchResponse =
+            serde_json::from_str(include_str!("../../tests/fixtures/mangadex_search.json"))
+                .unwrap();
+        let source = MangaDexSource::new();
+
+        let manga_list = source.map_search_response(response);
+        let manga = &manga_list[0];
+        assert_eq!(manga.title, "Fixture Manga");
+        assert_eq!(manga.content_rating, Some(ContentRating::Suggestive));
+        assert_eq!(manga.available_languages, vec!["en", "ja"]);
+
+        // Missing fields fall back to None and an empty list
+        let manga = &manga_list[1];
+        assert_eq!(manga.content_rating, None);
+        assert!(manga.available_languages.is_empty());
+    }
+
+    #[test]
+    fn test_map_manga_with_missing_fields() {
+        let source = MangaDexSource::new();
+        let payload = r#"{
+            "data": [
+                {
+                    "id": "complete",
+                    "type": "manga",
+                    "attributes": {
+                        "title": {"en": "Complete"},
+                        "altTitles": [],
+                        "description": {"en": "Has everything"},
+                        "status": "ongoing",
+                        "tags": []
+                    },
+                    "relationships": []
+                },
+                {
+                    "id": "sparse",
+                    "type": "manga",
+                    "attributes": {
+                        "title": {"en": "Sparse"},
+                        "status": "completed"
+                    },
+                    "relationships": []
+                }
+            ],
+            "total": 2,
+            "limit": 20,
+            "offset": 0
+        }"#;
+
+        let response: MangaDexSearchResponse = serde_json::from_str(payload).unwrap();
+        let manga = source.map_search_response(response);
+
+        assert_eq!(manga.len(), 2);
+        assert_eq!(manga[0].description.as_deref(), Some("Has everything"));
+        assert_eq!(manga[1].title, "Sparse");
+        assert_eq!(manga[1].description, None);
+        assert!(manga[1].tags.is_empty());
+    }
+
+    #[test]
+    fn test_search_skips_broken_entries() {
+        let source = MangaDexSource::new();
+        let entry = |id: &str| {
+            serde_json::json!({
+                "id": id,
+                "type": "manga",
+                "attributes": {"title": {"en": id}, "status": "ongoing"},
+                "relationships": []
+            })
+        };
+        let response = MangaDexSearchResponse {
+            data: vec![
+                entry("first"),
+                serde_json::json!({"id": "broken", "attributes": "not an object"}),
+                serde_json::json!(42),
+                entry("last"),
+            ],
+            total: 4,
+            limit: 20,
+            offset: 0,
+        };
+
+        let manga = source.map_search_response(response);
+        let ids: Vec<&str> = manga.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["first", "last"]);
+        assert_eq!(source.skipped_entries(), 2);
+    }
+
+    #[test]
+    fn test_error_envelope_becomes_source_error() {
+        let source = MangaDexSource::new();
+        let body = serde_json::json!({
+            "result": "error",
+            "errors": [{
+                "id": "abc",
+                "status": 404,
+                "title": "not_found_http_exception",
+                "detail": "Manga does not exist"
+            }]
+        });
+
+        let err = source
+            .parse_api_response::<MangaDexSearchResponse>(body)
+            .unwrap_err();
+        match err {
+            Error::Source {
+                src,
+                message,
+                status,
+                ..
+            } => {
+                assert_eq!(src, "mgd");
+                assert_eq!(message, "Manga does not exist");
+                assert_eq!(status, Some(404));
+            }
+            other => panic!("expected source error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_search_query_encoding() {
+        let source = MangaDexSource::new();
+
+        for query in ["tom & jerry", "a=b", "one piece", "🍥 naruto"] {
+            let formatted = source.format_search_query(query, &SearchParams::from(query));
+            let url =
+                url::Url::parse(&format!("https://api.mangadex.org/manga?{}", formatted)).unwrap();
+
+            // The title round-trips intact and no parameter leaks out of it
+            let titles: Vec<String> = url
+                .query_pairs()
+                .filter(|(key, _)| key == "title")
+                .map(|(_, value)| value.into_owned())
+                .collect();
+            assert_eq!(titles, vec![query.to_string()]);
+            assert!(
+                url.query_pairs()
+                    .all(|(key, _)| key != "b" && key != " jerry")
+            );
+            assert_eq!(
+                url.query_pairs()
+                    .filter(|(key, _)| key == "contentRating[]")
+                    .count(),
+                4
+            );
+        }
+    }
+
+    #[test]
+    fn test_empty_query_omits_title() {
+        let source = MangaDexSource::new();
+
+        for query in ["", "   "] {
+            let formatted = source.format_search_query(query, &SearchParams::from(query));
+            let url =
+                url::Url::parse(&format!("https://api.mangadex.org/manga?{}", formatted)).unwrap();
+
+            assert!(url.query_pairs().all(|(key, _)| key != "title"));
+            assert!(
+                url.query_pairs()
+                    .any(|(key, value)| key == "order[followedCount]" && value == "desc")
+            );
+            assert!(url.query_pairs().all(|(key, _)| key != "order[relevance]"));
+        }
+
+        // An explicit sort order still wins when browsing
+        let params = SearchParams {
+            sort_by: Some(SortOrder::UpdatedAt),
+            ..SearchParams::from("")
+        };
+        let formatted = source.format_search_query("", &params);
+        assert!(formatted.contains("order%5BupdatedAt%5D=desc"));
+        assert!(!formatted.contains("title="));
+    }
+
+    #[test]
+    fn test_explain_shows_search_url() {
+        let source = MangaDexSource::new();
+        let params = SearchParams {
+            limit: Some(5),
+            ..SearchParams::from("tom & jerry")
+        };
+
+        let explained = source.explain(&params);
+        assert_eq!(explained.len(), 1);
+        let (label, url) = &explained[0];
+        assert_eq!(label, "search");
+        assert!(url.starts_with("https://api.mangadex.org/manga?"));
+        assert!(url.contains("title=tom%20%26%20jerry"));
+        assert!(url.contains("limit=5"));
+    }
+
+    #[test]
+    fn test_explain_reports_unresolved_tags() {
+        let source = MangaDexSource::new();
+        let params = SearchParams {
+            include_tags: vec!["Action".to_string()],
+            exclude_tags: vec!["Gore".to_string()],
+            ..SearchParams::from("")
+        };
+
+        // Cold cache: the tag list fetch is listed and no name can be resolved yet
+        let explained = source.explain(&params);
+        let labels: Vec<&str> = explained.iter().map(|(label, _)| label.as_str()).collect();
+        assert_eq!(labels, ["tags", "search", "unresolved tags"]);
+        assert_eq!(explained[0].1, "https://api.mangadex.org/manga/tag");
+        assert_eq!(
+            explained[2].1,
+            "includedTags[]: Action; excludedTags[]: Gore"
+        );
+
+        // Warm cache: known names become IDs in the URL, unknown ones stay listed
+        let _ = source.tag_ids.set(HashMap::from([(
+            "action".to_string(),
+            "tag-action".to_string(),
+        )]));
+        let explained = source.explain(&params);
+        let labels: Vec<&str> = explained.iter().map(|(label, _)| label.as_str()).collect();
+        assert_eq!(labels, ["search", "unresolved tags"]);
+        assert!(explained[0].1.contains("includedTags%5B%5D=tag-action"));
+        assert_eq!(explained[1].1, "excludedTags[]: Gore");
+    }
+
+    #[test]
+    fn test_latest_query() {
+        let source = MangaDexSource::new();
+        let formatted = source.format_latest_query(15);
+        let url =
+            url::Url::parse(&format!("https://api.mangadex.org/manga?{}", formatted)).unwrap();
+
+        let pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        assert!(pairs.contains(&("limit".into(), "15".into())));
+        assert!(pairs.contains(&("order[latestUploadedChapter]".into(), "desc".into())));
+        assert!(pairs.iter().all(|(key, _)| key != "title"));
+    }
+
+    #[test]
+    fn test_map_chapter_page_count() {
+        let source = MangaDexSource::new();
+        let payload = r#"{
+            "id": "chapter-1",
+            "attributes": {
+                "title": "Romance Dawn",
+                "chapter": "1",
+                "volume": "1",
+                "publishAt": "2020-01-01T00:00:00+00:00",
+                "translatedLanguage": "en",
+                "pages": 53
+            },
+            "relationships": []
+        }"#;
+
+        let data: MangaDexChapterData = serde_json::from_str(payload).unwrap();
+        let chapter = source
+            .map_chapter_data_to_chapter(&data, "manga-1")
+            .unwrap();
+        assert_eq!(chapter.page_count, Some(53));
+        assert!(chapter.pages.is_empty());
+
+        // External chapters report zero pages, which means unknown
+        let external = payload.replace("\"pages\": 53", "\"pages\": 0");
+        let data: MangaDexChapterData = serde_json::from_str(&external).unwrap();
+        let chapter = source
+            .map_chapter_data_to_chapter(&data, "manga-1")
+            .unwrap();
+        assert_eq!(chapter.page_count, None);
+    }
+
+    #[test]
+    fn test_chapters_query_encoding() {
+        let source = MangaDexSource::new();
+        let query = source.format_chapters_query(500, 100);
+
+        assert!(query.starts_with("limit=100&offset=500&"));
+        assert!(query.contains("order%5Bchapter%5D=asc"));
+        assert!(query.contains("translatedLanguage%5B%5D=en"));
+    }
 }
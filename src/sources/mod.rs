@@ -46,7 +46,43 @@ pub mod kissmanga;
 
 // Re-export sources only when their features are enabled
 #[cfg(feature = "source-mangadex")]
-pub use mangadex::MangaDexSource;
+pub use mangadex::{MangaDexSource, MangaDexSourceBuilder};
 
 #[cfg(feature = "source-kissmanga")]
 pub use kissmanga::KissMangaSource;
+
+/// Builds a source instance from its ID.
+///
+/// This is a factory for tools that select sources at runtime, e.g. from a
+/// `--source mgd` command line flag. Only sources compiled into this build
+/// (see the feature flags above) can be constructed.
+///
+/// # Parameters
+///
+/// * `id` - The source ID, as returned by [`Source::id()`](crate::Source::id)
+///
+/// # Returns
+///
+/// The source, or `None` if the ID is unknown or its feature is disabled.
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::sources::build_by_id;
+///
+/// # #[cfg(feature = "source-mangadex")]
+/// # {
+/// let source = build_by_id("mgd").unwrap();
+/// assert_eq!(source.name(), "MangaDex");
+/// # }
+/// assert!(build_by_id("unknown").is_none());
+/// ```
+pub fn build_by_id(id: &str) -> Option<Box<dyn crate::Source>> {
+    match id {
+        #[cfg(feature = "source-mangadex")]
+        "mgd" => Some(Box::new(MangaDexSource::new())),
+        #[cfg(feature = "source-kissmanga")]
+        "kmg" => Some(Box::new(KissMangaSource::new())),
+        _ => None,
+    }
+}
@@ -4,8 +4,15 @@
 //!
 //! - [`Manga`] - Represents a manga/comic series with metadata
 //! - [`Chapter`] - Represents a single chapter with pages
+//! - [`ContentRating`] - Content rating of a manga
+//! - [`PageInfo`] - A page URL with optional layout and request metadata
+//! - [`MangaDto`] / [`ChapterDto`] - Feature-independent shapes for front-end interop
 //! - [`SearchParams`] - Parameters for searching manga
 //! - [`SortOrder`] - Sorting options for search results
+//! - [`normalize_title`] - Canonical title form for matching and deduplication
+//! - [`parse_chapter_range_against`] - Chapter selection from range specs like `1,5-8,50-`
+//! - [`parse_chapter_number_from_title`] - Chapter numbers from titles like `Ch. 10.5`
+//! - `specta_types` - All exported types, with the `specta` feature
 //!
 //! # Examples
 //!
@@ -21,11 +28,16 @@
 //!     cover_url: Some("https://example.com/cover.jpg".to_string()),
 //!     description: Some("Epic pirate adventure".to_string()),
 //!     tags: vec!["Action".to_string(), "Adventure".to_string()],
+//!     content_rating: None,
+//!     available_languages: vec![],
 //! };
 //! ```
 
 use derive_builder::Builder;
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
 
 #[cfg(feature = "sqlx")]
 use sqlx::FromRow;
@@ -45,6 +57,8 @@ use sqlx::FromRow;
 /// * `authors` - List of author names
 /// * `description` - Optional plot summary or description
 /// * `tags` - Genre tags and categories
+/// * `content_rating` - Optional content rating, for sources that provide one
+/// * `available_languages` - Languages with translations, for sources that provide them
 /// * `source_id` - Identifier of the source this manga came from
 ///
 /// # Examples
@@ -61,12 +75,36 @@ use sqlx::FromRow;
 ///     cover_url: Some("https://example.com/cover.jpg".to_string()),
 ///     description: Some("A story about pirates".to_string()),
 ///     tags: vec!["Action".to_string(), "Adventure".to_string()],
+///     content_rating: None,
+///     available_languages: vec![],
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// # Builder Usage
+///
+/// The `derive_builder` crate generates a `MangaBuilder`. Only `id`, `title`
+/// and `source_id` are required; every other field defaults to empty.
+///
+/// ```rust
+/// use tosho::types::MangaBuilder;
+///
+/// let manga = MangaBuilder::default()
+///     .id("123")
+///     .title("One Piece")
+///     .source_id("mangadex")
+///     .cover_url("https://example.com/cover.jpg")
+///     .tags(vec!["Action".to_string()])
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(manga.cover_url.as_deref(), Some("https://example.com/cover.jpg"));
+/// assert!(manga.authors.is_empty());
+/// ```
+#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
 #[cfg_attr(feature = "sqlx", derive(FromRow))]
 #[cfg_attr(feature = "sqlx", sqlx(rename_all = "snake_case"))]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
+#[builder(setter(into))]
 pub struct Manga {
     /// Unique identifier within the source
     pub id: String,
@@ -75,28 +113,330 @@ pub struct Manga {
     pub title: String,
 
     /// Cover image URL
+    #[builder(default, setter(strip_option))]
     pub cover_url: Option<String>,
 
     /// URL to the manga's main page
+    #[builder(default, setter(strip_option))]
     pub url: Option<String>,
 
     /// List of authors
     #[cfg_attr(feature = "sqlx", sqlx(skip))]
     #[serde(default)]
+    #[builder(default)]
     pub authors: Vec<String>,
 
     /// Description/summary
+    #[builder(default, setter(strip_option))]
     pub description: Option<String>,
 
     /// Tags/genres
     #[cfg_attr(feature = "sqlx", sqlx(skip))]
     #[serde(default)]
+    #[builder(default)]
     pub tags: Vec<String>,
 
+    /// Content rating, if the source provides one
+    #[cfg_attr(feature = "sqlx", sqlx(skip))]
+    #[serde(default)]
+    #[builder(default, setter(strip_option))]
+    pub content_rating: Option<ContentRating>,
+
+    /// Language codes the manga has translations in, if the source provides them
+    #[cfg_attr(feature = "sqlx", sqlx(skip))]
+    #[serde(default)]
+    #[builder(default)]
+    pub available_languages: Vec<String>,
+
     /// Source identifier this manga came from
     pub source_id: String,
 }
 
+impl Manga {
+    /// Enriches this record with data from another record of the same manga.
+    ///
+    /// Useful for upgrading a sparse search result with a more detailed record
+    /// without clobbering fields that are already set. `id` and `source_id` are
+    /// never changed.
+    ///
+    /// - Empty or `None` fields are filled from `other`
+    /// - `authors`, `tags` and `available_languages` become the union of both, without duplicates
+    /// - The longer of the two descriptions is kept
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::types::Manga;
+    /// # fn manga(description: Option<&str>, tags: &[&str]) -> Manga {
+    /// #     Manga {
+    /// #         id: "123".to_string(),
+    /// #         url: None,
+    /// #         title: "One Piece".to_string(),
+    /// #         authors: vec![],
+    /// #         source_id: "mangadex".to_string(),
+    /// #         cover_url: None,
+    /// #         description: description.map(String::from),
+    /// #         tags: tags.iter().map(|t| t.to_string()).collect(),
+    /// #         content_rating: None,
+    /// #         available_languages: vec![],
+    /// #     }
+    /// # }
+    ///
+    /// let mut stored = manga(None, &["Action"]);
+    /// let details = manga(Some("A story about pirates"), &["Action", "Adventure"]);
+    ///
+    /// stored.merge_from(&details);
+    /// assert_eq!(stored.description.as_deref(), Some("A story about pirates"));
+    /// assert_eq!(stored.tags, vec!["Action", "Adventure"]);
+    /// ```
+    pub fn merge_from(&mut self, other: &Manga) {
+        if self.title.trim().is_empty() {
+            self.title = other.title.clone();
+        }
+        if self.cover_url.is_none() {
+            self.cover_url = other.cover_url.clone();
+        }
+        if self.url.is_none() {
+            self.url = other.url.clone();
+        }
+        if self.content_rating.is_none() {
+            self.content_rating = other.content_rating;
+        }
+
+        let other_is_longer = match (&self.description, &other.description) {
+            (Some(current), Some(candidate)) => candidate.len() > current.len(),
+            (None, Some(_)) => true,
+            _ => false,
+        };
+        if other_is_longer {
+            self.description = other.description.clone();
+        }
+
+        let union = |target: &mut Vec<String>, extra: &[String]| {
+            for value in extra {
+                if !target.contains(value) {
+                    target.push(value.clone());
+                }
+            }
+        };
+        union(&mut self.authors, &other.authors);
+        union(&mut self.tags, &other.tags);
+        union(&mut self.available_languages, &other.available_languages);
+    }
+
+    /// Returns a filesystem-safe identifier for this manga.
+    ///
+    /// The slug has the form `{title}-{source_id}-{hash}`: a lowercase,
+    /// dash-separated form of the title for readability, the source ID, and
+    /// eight hex digits derived from the manga ID. Two manga with the same title
+    /// therefore get different slugs, whether they come from different sources
+    /// or are different entries of the same one.
+    ///
+    /// Only the `{source_id}-{hash}` suffix is stable. The slug follows the
+    /// title, so when a source retitles a series its slug, and with it the
+    /// download directory, changes too. Match on the suffix to find
+    /// directories created under an earlier title.
+    ///
+    /// Used for download directory names, see
+    /// [`chapter_dir`](crate::download::chapter_dir).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::types::Manga;
+    /// # fn manga(id: &str, source_id: &str) -> Manga {
+    /// #     Manga {
+    /// #         id: id.to_string(),
+    /// #         url: None,
+    /// #         title: "One Piece: East Blue".to_string(),
+    /// #         authors: vec![],
+    /// #         source_id: source_id.to_string(),
+    /// #         cover_url: None,
+    /// #         description: None,
+    /// #         tags: vec![],
+    /// #         content_rating: None,
+    /// #         available_languages: vec![],
+    /// #     }
+    /// # }
+    ///
+    /// let slug = manga("a1b2", "mgd").slug();
+    /// assert!(slug.starts_with("one-piece-east-blue-mgd-"));
+    /// assert_ne!(slug, manga("a1b2", "kmg").slug());
+    ///
+    /// // A new title changes the slug, but not its source and hash suffix
+    /// let mut retitled = manga("a1b2", "mgd");
+    /// retitled.title = "One Piece".to_string();
+    /// let suffix = slug.trim_start_matches("one-piece-east-blue");
+    /// assert_eq!(retitled.slug(), format!("one-piece{}", suffix));
+    /// ```
+    pub fn slug(&self) -> String {
+        // FNV-1a, so the hash stays the same across platforms and Rust versions
+        let hash = self.id.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+            (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+        });
+
+        let mut title = slugify(&self.title, 64);
+        if title.is_empty() {
+            title = "untitled".to_string();
+        }
+
+        format!("{}-{}-{:08x}", title, slugify(&self.source_id, 32), hash)
+    }
+
+    /// Returns `true` if both records describe the same manga with the same metadata.
+    ///
+    /// Meant for change detection, e.g. deciding whether a freshly fetched
+    /// record needs to be written back to storage. Every field takes part in
+    /// the comparison, and list fields must also match in order. Fields that
+    /// change without the metadata changing, such as fetch timestamps, are
+    /// left out when added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::types::MangaBuilder;
+    ///
+    /// let stored = MangaBuilder::default()
+    ///     .id("op")
+    ///     .title("One Piece")
+    ///     .source_id("mgd")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut fetched = stored.clone();
+    /// assert!(stored.content_eq(&fetched));
+    ///
+    /// fetched.tags.push("Adventure".to_string());
+    /// assert!(!stored.content_eq(&fetched));
+    /// ```
+    pub fn content_eq(&self, other: &Manga) -> bool {
+        // Destructured so that new fields have to be considered here
+        let Manga {
+            id,
+            title,
+            cover_url,
+            url,
+            authors,
+            description,
+            tags,
+            content_rating,
+            available_languages,
+            source_id,
+        } = self;
+
+        *id == other.id
+            && *source_id == other.source_id
+            && *title == other.title
+            && *cover_url == other.cover_url
+            && *url == other.url
+            && *authors == other.authors
+            && *description == other.description
+            && *tags == other.tags
+            && *content_rating == other.content_rating
+            && *available_languages == other.available_languages
+    }
+}
+
+/// Formats a manga as `title [source_id]`, for logs and CLI output.
+///
+/// # Examples
+///
+/// ```rust
+/// # use tosho::types::Manga;
+/// # let manga = Manga {
+/// #     id: "op".to_string(),
+/// #     url: None,
+/// #     title: "One Piece".to_string(),
+/// #     authors: vec![],
+/// #     source_id: "mgd".to_string(),
+/// #     cover_url: None,
+/// #     description: None,
+/// #     tags: vec![],
+/// #     content_rating: None,
+/// #     available_languages: vec![],
+/// # };
+/// assert_eq!(manga.to_string(), "One Piece [mgd]");
+/// ```
+impl std::fmt::Display for Manga {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} [{}]", self.title, self.source_id)
+    }
+}
+
+/// Lowercases `text` and joins its alphanumeric runs with single dashes,
+/// keeping at most `max_chars` characters.
+fn slugify(text: &str, max_chars: usize) -> String {
+    let mut slug = String::new();
+    for word in text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.extend(word.chars().flat_map(char::to_lowercase));
+    }
+
+    match slug.char_indices().nth(max_chars) {
+        Some((end, _)) => slug[..end].trim_end_matches('-').to_string(),
+        None => slug,
+    }
+}
+
+/// Content rating of a manga, as reported by its source.
+///
+/// Not every source rates its content; [`Manga::content_rating`] is `None`
+/// for those that don't.
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::types::ContentRating;
+///
+/// let rating: ContentRating = "suggestive".parse().unwrap();
+/// assert_eq!(rating, ContentRating::Suggestive);
+/// assert_eq!(rating.as_str(), "suggestive");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "lowercase")]
+pub enum ContentRating {
+    Safe,
+    Suggestive,
+    Erotica,
+    Pornographic,
+}
+
+impl ContentRating {
+    /// Returns the lowercase name of the rating.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentRating::Safe => "safe",
+            ContentRating::Suggestive => "suggestive",
+            ContentRating::Erotica => "erotica",
+            ContentRating::Pornographic => "pornographic",
+        }
+    }
+}
+
+impl std::str::FromStr for ContentRating {
+    type Err = crate::Error;
+
+    /// Parses a rating name case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "safe" => Ok(ContentRating::Safe),
+            "suggestive" => Ok(ContentRating::Suggestive),
+            "erotica" => Ok(ContentRating::Erotica),
+            "pornographic" => Ok(ContentRating::Pornographic),
+            other => Err(crate::Error::parse(format!(
+                "Unknown content rating: {}",
+                other
+            ))),
+        }
+    }
+}
+
 /// Represents a single chapter of a manga.
 ///
 /// Chapters contain the actual readable content in the form of page URLs.
@@ -108,6 +448,7 @@ pub struct Manga {
 /// * `number` - Chapter number (supports decimals for special chapters)
 /// * `title` - Chapter title or name
 /// * `pages` - URLs to individual pages of the chapter
+/// * `page_count` - Number of pages, if the source lists it without resolving the pages
 /// * `manga_id` - ID of the manga this chapter belongs to
 /// * `source_id` - Identifier of the source
 ///
@@ -125,14 +466,37 @@ pub struct Manga {
 ///         "https://example.com/page1.jpg".to_string(),
 ///         "https://example.com/page2.jpg".to_string(),
 ///     ],
+///     page_count: None,
 ///     manga_id: "one-piece".to_string(),
 ///     source_id: "mangadex".to_string(),
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// # Builder Usage
+///
+/// The `derive_builder` crate generates a `ChapterBuilder`. `id`, `number`,
+/// `manga_id` and `source_id` are required; every other field defaults to empty.
+///
+/// ```rust
+/// use tosho::types::ChapterBuilder;
+///
+/// let chapter = ChapterBuilder::default()
+///     .id("ch1")
+///     .number(1.0)
+///     .title("Romance Dawn")
+///     .manga_id("one-piece")
+///     .source_id("mangadex")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(chapter.volume, None);
+/// assert!(chapter.pages.is_empty());
+/// ```
+#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
 #[cfg_attr(feature = "sqlx", derive(FromRow))]
 #[cfg_attr(feature = "sqlx", sqlx(rename_all = "snake_case"))]
 #[cfg_attr(feature = "specta", derive(specta::Type))]
+#[builder(setter(into))]
 pub struct Chapter {
     /// Unique identifier within the source
     pub id: String,
@@ -142,16 +506,25 @@ pub struct Chapter {
 
     /// Volume number, if available
     #[serde(default)]
+    #[builder(default, setter(strip_option))]
     pub volume: Option<f64>,
 
     /// Chapter title
+    #[builder(default)]
     pub title: String,
 
     /// Page URLs for this chapter
     #[cfg_attr(feature = "sqlx", sqlx(skip))]
     #[serde(default)]
+    #[builder(default)]
     pub pages: Vec<String>,
 
+    /// Number of pages, if known before the pages are fetched
+    #[cfg_attr(feature = "sqlx", sqlx(skip))]
+    #[serde(default)]
+    #[builder(default, setter(strip_option))]
+    pub page_count: Option<usize>,
+
     /// Associated manga ID
     pub manga_id: String,
 
@@ -159,6 +532,324 @@ pub struct Chapter {
     pub source_id: String,
 }
 
+impl Chapter {
+    /// Compares two chapters by their chapter number.
+    ///
+    /// Chapter numbers are `f64`, which has no total order of its own. This uses
+    /// [`f64::total_cmp`] so that sorting is deterministic even when a source
+    /// produces a `NaN` number: `NaN` chapters always sort after every real one.
+    ///
+    /// Chapters with the same number (e.g. a chapter re-released in a later
+    /// volume) are ordered by volume, with chapters without a volume last.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::types::Chapter;
+    /// # fn chapter(number: f64) -> Chapter {
+    /// #     Chapter {
+    /// #         id: number.to_string(),
+    /// #         number,
+    /// #         volume: None,
+    /// #         title: String::new(),
+    /// #         pages: vec![],
+    /// #         page_count: None,
+    /// #         manga_id: "manga".to_string(),
+    /// #         source_id: "source".to_string(),
+    /// #     }
+    /// # }
+    ///
+    /// let mut chapters = vec![chapter(2.0), chapter(1.0), chapter(1.5)];
+    /// chapters.sort_by(Chapter::cmp_by_number);
+    ///
+    /// let numbers: Vec<f64> = chapters.iter().map(|c| c.number).collect();
+    /// assert_eq!(numbers, vec![1.0, 1.5, 2.0]);
+    /// ```
+    pub fn cmp_by_number(&self, other: &Chapter) -> std::cmp::Ordering {
+        // Normalise the sign of NaN so every NaN sorts after real numbers
+        let key = |n: f64| if n.is_nan() { f64::NAN.abs() } else { n };
+        let volume = |v: Option<f64>| key(v.unwrap_or(f64::NAN));
+        key(self.number)
+            .total_cmp(&key(other.number))
+            .then_with(|| volume(self.volume).total_cmp(&volume(other.volume)))
+    }
+
+    /// Returns `true` if both records describe the same chapter with the same metadata.
+    ///
+    /// Meant for change detection, like [`Manga::content_eq`]. Page URLs are
+    /// ignored: many sources hand out short-lived or per-server image URLs,
+    /// so they differ between fetches of an unchanged chapter. Numbers are
+    /// compared exactly, treating two `NaN` numbers as equal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::types::ChapterBuilder;
+    ///
+    /// let stored = ChapterBuilder::default()
+    ///     .id("ch-1")
+    ///     .number(1.0)
+    ///     .manga_id("op")
+    ///     .source_id("mgd")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut fetched = stored.clone();
+    /// fetched.pages = vec!["https://cdn.example.com/1.jpg".to_string()];
+    /// assert!(stored.content_eq(&fetched));
+    ///
+    /// fetched.title = "Romance Dawn".to_string();
+    /// assert!(!stored.content_eq(&fetched));
+    /// ```
+    pub fn content_eq(&self, other: &Chapter) -> bool {
+        // Destructured so that new fields have to be considered here
+        let Chapter {
+            id,
+            number,
+            volume,
+            title,
+            pages: _,
+            page_count,
+            manga_id,
+            source_id,
+        } = self;
+
+        let same_number = |a: f64, b: f64| a == b || (a.is_nan() && b.is_nan());
+        let same_volume = match (volume, other.volume) {
+            (Some(a), Some(b)) => same_number(*a, b),
+            (a, b) => a.is_none() && b.is_none(),
+        };
+
+        *id == other.id
+            && *source_id == other.source_id
+            && *manga_id == other.manga_id
+            && same_number(*number, other.number)
+            && same_volume
+            && *title == other.title
+            && *page_count == other.page_count
+    }
+}
+
+/// Formats a chapter as `Ch. number: title`, for logs and CLI output.
+///
+/// Whole numbers are shown without decimals, and the title is left out when
+/// it is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// # use tosho::types::Chapter;
+/// # let mut chapter = Chapter {
+/// #     id: "ch".to_string(),
+/// #     number: 12.5,
+/// #     volume: None,
+/// #     title: "Omake".to_string(),
+/// #     pages: vec![],
+/// #     page_count: None,
+/// #     manga_id: "manga".to_string(),
+/// #     source_id: "source".to_string(),
+/// # };
+/// assert_eq!(chapter.to_string(), "Ch. 12.5: Omake");
+///
+/// chapter.number = 13.0;
+/// chapter.title = String::new();
+/// assert_eq!(chapter.to_string(), "Ch. 13");
+/// ```
+impl std::fmt::Display for Chapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Ch. {}", self.number)?;
+        if !self.title.trim().is_empty() {
+            write!(f, ": {}", self.title)?;
+        }
+        Ok(())
+    }
+}
+
+/// A single chapter page with the metadata a reader needs before loading it.
+///
+/// Returned by [`Source::get_pages_detailed()`](crate::Source::get_pages_detailed).
+/// Sources fill in whatever they know without fetching the image itself;
+/// everything but the URL is optional.
+///
+/// # Fields
+///
+/// * `url` - Direct URL of the page image
+/// * `width` - Width in pixels, if the source reports it
+/// * `height` - Height in pixels, if the source reports it
+/// * `referer` - `Referer` header the image host expects, if any
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::types::PageInfo;
+///
+/// let page = PageInfo::from("https://example.com/page1.jpg".to_string());
+/// assert_eq!(page.width, None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct PageInfo {
+    pub url: String,
+    #[serde(default)]
+    pub width: Option<u32>,
+    #[serde(default)]
+    pub height: Option<u32>,
+    #[serde(default)]
+    pub referer: Option<String>,
+}
+
+impl From<String> for PageInfo {
+    /// Creates page info with only the URL set
+    fn from(url: String) -> Self {
+        Self {
+            url,
+            width: None,
+            height: None,
+            referer: None,
+        }
+    }
+}
+
+/// New chapters found for a followed manga by [`Sources::check_updates()`](crate::Sources::check_updates).
+///
+/// # Fields
+///
+/// * `source_id` - Identifier of the source the manga belongs to
+/// * `manga_id` - ID of the manga within the source
+/// * `last_known` - The last chapter number the caller already knew about
+/// * `new_chapters` - Chapters numbered above `last_known`, in ascending order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct ChapterUpdate {
+    pub source_id: String,
+    pub manga_id: String,
+    pub last_known: f64,
+    pub new_chapters: Vec<Chapter>,
+}
+
+/// Stable, serializable view of a [`Manga`] for front-ends.
+///
+/// [`Manga`] carries database attributes when the `sqlx` feature is enabled,
+/// so its generated bindings can differ between builds. `MangaDto` has no
+/// feature-gated fields or attributes: with the `specta` feature it always
+/// exports the same TypeScript type, and its JSON shape never changes.
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::types::{Manga, MangaDto};
+///
+/// let manga = Manga {
+///     id: "123".to_string(),
+///     url: None,
+///     title: "One Piece".to_string(),
+///     authors: vec!["Oda Eiichiro".to_string()],
+///     source_id: "mangadex".to_string(),
+///     cover_url: None,
+///     description: None,
+///     tags: vec!["Action".to_string()],
+///     content_rating: None,
+///     available_languages: vec![],
+/// };
+///
+/// let dto = MangaDto::from(manga);
+/// assert_eq!(dto.title, "One Piece");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct MangaDto {
+    pub id: String,
+    pub title: String,
+    pub cover_url: Option<String>,
+    pub url: Option<String>,
+    pub authors: Vec<String>,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub content_rating: Option<ContentRating>,
+    pub available_languages: Vec<String>,
+    pub source_id: String,
+}
+
+impl From<Manga> for MangaDto {
+    fn from(manga: Manga) -> Self {
+        Self {
+            id: manga.id,
+            title: manga.title,
+            cover_url: manga.cover_url,
+            url: manga.url,
+            authors: manga.authors,
+            description: manga.description,
+            tags: manga.tags,
+            content_rating: manga.content_rating,
+            available_languages: manga.available_languages,
+            source_id: manga.source_id,
+        }
+    }
+}
+
+impl From<MangaDto> for Manga {
+    fn from(dto: MangaDto) -> Self {
+        Self {
+            id: dto.id,
+            title: dto.title,
+            cover_url: dto.cover_url,
+            url: dto.url,
+            authors: dto.authors,
+            description: dto.description,
+            tags: dto.tags,
+            content_rating: dto.content_rating,
+            available_languages: dto.available_languages,
+            source_id: dto.source_id,
+        }
+    }
+}
+
+/// Stable, serializable view of a [`Chapter`] for front-ends.
+///
+/// The counterpart of [`MangaDto`] for chapters; see there for why it exists.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct ChapterDto {
+    pub id: String,
+    pub number: f64,
+    pub volume: Option<f64>,
+    pub title: String,
+    pub pages: Vec<String>,
+    pub page_count: Option<usize>,
+    pub manga_id: String,
+    pub source_id: String,
+}
+
+impl From<Chapter> for ChapterDto {
+    fn from(chapter: Chapter) -> Self {
+        Self {
+            id: chapter.id,
+            number: chapter.number,
+            volume: chapter.volume,
+            title: chapter.title,
+            pages: chapter.pages,
+            page_count: chapter.page_count,
+            manga_id: chapter.manga_id,
+            source_id: chapter.source_id,
+        }
+    }
+}
+
+impl From<ChapterDto> for Chapter {
+    fn from(dto: ChapterDto) -> Self {
+        Self {
+            id: dto.id,
+            number: dto.number,
+            volume: dto.volume,
+            title: dto.title,
+            pages: dto.pages,
+            page_count: dto.page_count,
+            manga_id: dto.manga_id,
+            source_id: dto.source_id,
+        }
+    }
+}
+
 /// Search parameters for querying manga across sources.
 ///
 /// This struct contains all the parameters that can be used to search for manga.
@@ -222,6 +913,7 @@ pub struct SearchParams {
 /// * `UpdatedAt` - Sort by when the manga was last updated (newest first)
 /// * `CreatedAt` - Sort by when the manga was first published
 /// * `Title` - Sort alphabetically by title
+/// * `Popularity` - Sort by how many readers follow the manga (most popular first)
 ///
 /// # Examples
 ///
@@ -241,6 +933,91 @@ pub enum SortOrder {
     UpdatedAt,
     CreatedAt,
     Title,
+    Popularity,
+}
+
+/// Collects every type exported to front-ends through `specta`.
+///
+/// Pass the collection to a `specta` exporter to generate bindings for all
+/// of Tosho's serializable types in one go. Enums are exported as unions of
+/// the string values they serialize to.
+///
+/// # Returns
+///
+/// A `specta::TypeCollection` containing each exported type
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let types = tosho::types::specta_types();
+/// // hand `types` to e.g. specta_typescript::Typescript::export
+/// ```
+#[cfg(feature = "specta")]
+pub fn specta_types() -> specta::TypeCollection {
+    let mut types = specta::TypeCollection::default();
+    types
+        .register::<Manga>()
+        .register::<Chapter>()
+        .register::<ContentRating>()
+        .register::<PageInfo>()
+        .register::<ChapterUpdate>()
+        .register::<MangaDto>()
+        .register::<ChapterDto>()
+        .register::<SearchParams>()
+        .register::<SortOrder>();
+    types
+}
+
+impl SearchParams {
+    /// Fills unset fields from a set of default parameters.
+    ///
+    /// Any value explicitly set on `self` is kept; only `None` options and empty
+    /// strings or vectors are taken from `defaults`. Tag lists are replaced, not
+    /// appended: a non-empty `include_tags` or `exclude_tags` on `self` wins
+    /// entirely over the corresponding list in `defaults`.
+    ///
+    /// # Parameters
+    ///
+    /// * `defaults` - The parameters to inherit unset values from
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tosho::types::SearchParams;
+    ///
+    /// let defaults = SearchParams {
+    ///     limit: Some(50),
+    ///     exclude_tags: vec!["Ecchi".to_string()],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let params = SearchParams::from("one piece").merge_defaults(&defaults);
+    /// assert_eq!(params.query, "one piece");
+    /// assert_eq!(params.limit, Some(50));
+    /// assert_eq!(params.exclude_tags, vec!["Ecchi".to_string()]);
+    /// ```
+    pub fn merge_defaults(self, defaults: &SearchParams) -> SearchParams {
+        SearchParams {
+            query: if self.query.is_empty() {
+                defaults.query.clone()
+            } else {
+                self.query
+            },
+            limit: self.limit.or(defaults.limit),
+            offset: self.offset.or(defaults.offset),
+            include_tags: if self.include_tags.is_empty() {
+                defaults.include_tags.clone()
+            } else {
+                self.include_tags
+            },
+            exclude_tags: if self.exclude_tags.is_empty() {
+                defaults.exclude_tags.clone()
+            } else {
+                self.exclude_tags
+            },
+            sort_by: self.sort_by.or_else(|| defaults.sort_by.clone()),
+        }
+    }
 }
 
 impl From<String> for SearchParams {
@@ -289,6 +1066,225 @@ impl From<&str> for SearchParams {
     }
 }
 
+/// Edition markers dropped from the end of a title by [`normalize_title`].
+///
+/// Longer markers come first so that e.g. "official colored" is removed as a
+/// whole rather than leaving "official" behind.
+const EDITION_MARKERS: &[&str] = &[
+    "official colored",
+    "digital colored",
+    "full color",
+    "colored",
+    "coloured",
+    "official",
+    "remastered",
+    "uncensored",
+    "digital",
+];
+
+/// Normalizes a manga title into a canonical form for matching.
+///
+/// Different sources format the same title differently, so comparisons should go
+/// through this function rather than ad-hoc lowercasing. The normalized form:
+///
+/// - is lowercase
+/// - has diacritics stripped (`"Pokémon"` becomes `"pokemon"`)
+/// - has parenthesized and bracketed segments removed (`"(Official Colored)"`, `"[Digital]"`)
+/// - has trailing edition markers such as `"colored"` or `"official"` removed
+/// - has whitespace collapsed to single spaces and trimmed
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::types::normalize_title;
+///
+/// assert_eq!(normalize_title("One Piece (Official Colored)"), "one piece");
+/// assert_eq!(normalize_title("  Pokémon   Adventures "), "pokemon adventures");
+/// assert_eq!(normalize_title("Berserk - Colored"), "berserk");
+/// ```
+pub fn normalize_title(title: &str) -> String {
+    // Strip diacritics by decomposing and dropping combining marks
+    let stripped: String = title.nfd().filter(|c| !is_combining_mark(*c)).collect();
+
+    // Drop anything inside (), [] or {}
+    let mut without_brackets = String::with_capacity(stripped.len());
+    let mut depth = 0usize;
+    for c in stripped.chars() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => without_brackets.push(c),
+            _ => {}
+        }
+    }
+
+    let mut normalized = without_brackets
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    // Repeatedly strip trailing edition markers and leftover separators
+    loop {
+        let trimmed = normalized
+            .trim_end_matches(|c: char| c.is_whitespace() || matches!(c, '-' | ':' | '|' | ','));
+        let marker = EDITION_MARKERS.iter().find(|marker| {
+            trimmed.len() > marker.len()
+                && trimmed.ends_with(*marker)
+                && trimmed[..trimmed.len() - marker.len()].ends_with(' ')
+        });
+
+        match marker {
+            Some(marker) => normalized = trimmed[..trimmed.len() - marker.len()].to_string(),
+            None => {
+                normalized = trimmed.to_string();
+                break;
+            }
+        }
+    }
+
+    normalized
+}
+
+/// Selects the chapter numbers matching a range spec from the available ones.
+///
+/// The spec is a comma-separated list of items, each one of:
+///
+/// - `N` - exactly chapter `N`
+/// - `A-B` - every chapter from `A` to `B`, inclusive
+/// - `A-` - every chapter from `A` onward
+/// - `-B` - every chapter up to `B`
+///
+/// Ranges filter `available` instead of counting up from `A`, so decimal
+/// chapters such as `5.5` are included in `5-8`.
+///
+/// # Parameters
+///
+/// * `spec` - The range spec, e.g. `"1,3,5-8,50-"`
+/// * `available` - The chapter numbers to select from
+///
+/// # Returns
+///
+/// The selected numbers in ascending order, without duplicates.
+///
+/// # Errors
+///
+/// Returns [`Error::Parse`](crate::Error::Parse) if an item isn't a number or
+/// range, or a range ends before it starts.
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::types::parse_chapter_range_against;
+///
+/// let available = [1.0, 2.0, 5.0, 5.5, 6.0, 50.0, 51.0];
+///
+/// let selected = parse_chapter_range_against("1, 5-6, 50-", &available).unwrap();
+/// assert_eq!(selected, vec![1.0, 5.0, 5.5, 6.0, 50.0, 51.0]);
+///
+/// let selected = parse_chapter_range_against("-2", &available).unwrap();
+/// assert_eq!(selected, vec![1.0, 2.0]);
+/// ```
+pub fn parse_chapter_range_against(spec: &str, available: &[f64]) -> crate::Result<Vec<f64>> {
+    let parse_bound = |bound: &str, item: &str| -> crate::Result<Option<f64>> {
+        let bound = bound.trim();
+        if bound.is_empty() {
+            return Ok(None);
+        }
+        match bound.parse::<f64>() {
+            Ok(number) if number.is_finite() => Ok(Some(number)),
+            _ => Err(crate::Error::parse(format!(
+                "Invalid chapter range item: {:?}",
+                item
+            ))),
+        }
+    };
+
+    let mut ranges = Vec::new();
+    for item in spec
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+    {
+        let (start, end) = match item.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse_bound(start, item)?, parse_bound(end, item)?);
+                if start.is_none() && end.is_none() {
+                    return Err(crate::Error::parse(format!(
+                        "Invalid chapter range item: {:?}",
+                        item
+                    )));
+                }
+                (start, end)
+            }
+            None => {
+                let number = parse_bound(item, item)?;
+                (number, number)
+            }
+        };
+
+        if let (Some(start), Some(end)) = (start, end)
+            && start > end
+        {
+            return Err(crate::Error::parse(format!(
+                "Chapter range ends before it starts: {:?}",
+                item
+            )));
+        }
+        ranges.push((start, end));
+    }
+
+    let mut selected: Vec<f64> = available
+        .iter()
+        .copied()
+        .filter(|number| {
+            ranges.iter().any(|&(start, end)| {
+                start.is_none_or(|start| *number >= start) && end.is_none_or(|end| *number <= end)
+            })
+        })
+        .collect();
+    selected.sort_by(f64::total_cmp);
+    selected.dedup();
+
+    Ok(selected)
+}
+
+/// Matches the chapter number in titles like "Ch. 10.5", "Chapter 10.5" or "#10.5".
+static CHAPTER_NUMBER_IN_TITLE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)(?:\bch(?:apter)?\b\.?|#)\s*(\d+(?:\.\d+)?)").unwrap());
+
+/// Extracts a chapter number from a chapter title.
+///
+/// Some sources only put the real chapter number in the title, leaving their
+/// numeric field empty. Recognized forms are `Ch. 10.5`, `Chapter 10.5` and
+/// `#10.5`, case-insensitively and anywhere in the title. A bare number
+/// without one of these markers is not taken as the chapter number, since it
+/// may be part of the title itself.
+///
+/// # Parameters
+///
+/// * `title` - The chapter title to search
+///
+/// # Returns
+///
+/// The first chapter number found, or `None` if the title doesn't contain one.
+///
+/// # Examples
+///
+/// ```rust
+/// use tosho::types::parse_chapter_number_from_title;
+///
+/// assert_eq!(parse_chapter_number_from_title("Chapter 10.5 - Omake"), Some(10.5));
+/// assert_eq!(parse_chapter_number_from_title("Vol. 2 Ch. 7"), Some(7.0));
+/// assert_eq!(parse_chapter_number_from_title("#12"), Some(12.0));
+/// assert_eq!(parse_chapter_number_from_title("Omake"), None);
+/// ```
+pub fn parse_chapter_number_from_title(title: &str) -> Option<f64> {
+    CHAPTER_NUMBER_IN_TITLE
+        .captures(title)
+        .and_then(|captures| captures[1].parse().ok())
+}
+
 /// Represents the target image format for conversion.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ImageFormat {
@@ -3,9 +3,17 @@
 //! Shared functionality used across all test modules.
 // Common test utilities and constants - all must be public
 
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+use async_trait::async_trait;
+use tosho::net::HttpClient;
+use tosho::prelude::*;
+
 #[allow(dead_code)]
 pub const TEST_DOWNLOADS_DIR: &str = "tests/downloads";
 #[allow(dead_code)]
@@ -110,3 +118,344 @@ pub async fn calculate_dir_size(dir: &std::path::Path) -> Result<u64, std::io::E
 
     Ok(total_size)
 }
+
+/// A canned HTTP response served by [`spawn_test_server`]
+#[allow(dead_code)]
+pub struct TestResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+#[allow(dead_code)]
+impl TestResponse {
+    /// A `200 OK` response with the given body
+    pub fn ok(body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status: 200,
+            headers: vec![],
+            body: body.into(),
+        }
+    }
+}
+
+/// Headers of each request received by a [`TestServer`], in arrival order
+pub type RequestHeaderLog = Arc<Mutex<Vec<Vec<(String, String)>>>>;
+
+/// Handle to a running local test server
+#[allow(dead_code)]
+pub struct TestServer {
+    pub base_url: String,
+    pub requests: Arc<AtomicUsize>,
+    pub request_headers: RequestHeaderLog,
+}
+
+#[allow(dead_code)]
+impl TestServer {
+    /// Full URL for a path on this server
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.base_url, path.trim_start_matches('/'))
+    }
+
+    /// Number of requests served so far
+    pub fn request_count(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+
+    /// Value of a header (case-insensitive) sent with the request at the given index
+    pub fn request_header(&self, index: usize, name: &str) -> Option<String> {
+        self.request_headers
+            .lock()
+            .unwrap()
+            .get(index)?
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.clone())
+    }
+}
+
+/// Spawns a minimal HTTP/1.1 server on a random local port
+/// The handler receives the zero-based request index and the request path
+/// Runs on a plain thread so tests don't need network access or extra tokio features
+#[allow(dead_code)]
+pub fn spawn_test_server<F>(handler: F) -> TestServer
+where
+    F: Fn(usize, &str) -> TestResponse + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    let request_headers: RequestHeaderLog = Arc::new(Mutex::new(Vec::new()));
+    let header_log = request_headers.clone();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request_line = String::new();
+            if reader.read_line(&mut request_line).is_err() {
+                continue;
+            }
+            let path = request_line
+                .split_whitespace()
+                .nth(1)
+                .unwrap_or("/")
+                .to_string();
+
+            // Read the request headers
+            let mut headers = Vec::new();
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok() && line.trim() != "" {
+                if let Some((name, value)) = line.trim().split_once(':') {
+                    headers.push((name.trim().to_string(), value.trim().to_string()));
+                }
+                line.clear();
+            }
+
+            header_log.lock().unwrap().push(headers);
+            let index = counter.fetch_add(1, Ordering::SeqCst);
+            let response = handler(index, &path);
+
+            let mut head = format!(
+                "HTTP/1.1 {} Test\r\nContent-Length: {}\r\nConnection: close\r\n",
+                response.status,
+                response.body.len()
+            );
+            for (name, value) in &response.headers {
+                head.push_str(&format!("{}: {}\r\n", name, value));
+            }
+            head.push_str("\r\n");
+
+            let _ = stream.write_all(head.as_bytes());
+            let _ = stream.write_all(&response.body);
+            let _ = stream.flush();
+        }
+    });
+
+    TestServer {
+        base_url,
+        requests,
+        request_headers,
+    }
+}
+
+/// Search responder for [`MockSource::with_search`]
+pub type SearchFn = Box<dyn Fn(&SearchParams) -> Vec<Manga> + Send + Sync>;
+
+/// Offline source returning fixed data, for exercising default `Source` methods
+/// and the multi-source search paths
+///
+/// The builder knobs cover what tests need to vary: ID, capabilities, a delay
+/// before answering, failures and computed search results. `calls`,
+/// `in_flight` and `peak` count `search()`/`get_chapters()` calls so tests
+/// can check which sources were queried, cancelled or run concurrently.
+#[allow(dead_code)]
+pub struct MockSource {
+    pub id: &'static str,
+    pub manga: Vec<Manga>,
+    pub chapters: Vec<Chapter>,
+    pub pages: Vec<String>,
+    pub client: Option<HttpClient>,
+    pub capabilities: &'static [Capability],
+    pub delay: Duration,
+    pub failures: usize,
+    pub error: fn() -> tosho::Error,
+    pub search_fn: Option<SearchFn>,
+    pub calls: Arc<AtomicUsize>,
+    pub in_flight: Arc<AtomicUsize>,
+    pub peak: Arc<AtomicUsize>,
+}
+
+/// Decrements a [`MockSource`] in-flight counter when a call finishes or is dropped
+struct InFlight(Arc<AtomicUsize>);
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[allow(dead_code)]
+impl MockSource {
+    /// A mock source whose chapters all resolve to the given page URLs
+    pub fn with_pages(pages: Vec<String>) -> Self {
+        Self {
+            id: "mock",
+            manga: vec![],
+            chapters: vec![],
+            pages,
+            client: None,
+            capabilities: &[],
+            delay: Duration::ZERO,
+            failures: 0,
+            error: || tosho::Error::source("mock", "unavailable"),
+            search_fn: None,
+            calls: Arc::new(AtomicUsize::new(0)),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            peak: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// A mock source with the given ID and no data
+    pub fn named(id: &'static str) -> Self {
+        Self {
+            id,
+            ..Self::with_pages(vec![])
+        }
+    }
+
+    /// Returns these manga from every search
+    pub fn with_manga(mut self, manga: Vec<Manga>) -> Self {
+        self.manga = manga;
+        self
+    }
+
+    /// Computes search results from the parameters instead of returning `manga`
+    pub fn with_search<F>(mut self, search: F) -> Self
+    where
+        F: Fn(&SearchParams) -> Vec<Manga> + Send + Sync + 'static,
+    {
+        self.search_fn = Some(Box::new(search));
+        self
+    }
+
+    /// Reports the given capabilities
+    pub fn with_capabilities(mut self, capabilities: &'static [Capability]) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    /// Waits this long before answering `search()` and `get_chapters()`
+    /// `Duration::MAX` never answers
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Fails every search with the given error
+    pub fn failing(self, error: fn() -> tosho::Error) -> Self {
+        self.failing_times(usize::MAX, error)
+    }
+
+    /// Fails the first `failures` searches with the given error, then answers normally
+    pub fn failing_times(mut self, failures: usize, error: fn() -> tosho::Error) -> Self {
+        self.failures = failures;
+        self.error = error;
+        self
+    }
+
+    /// Exposes the given client through `Source::http_client`
+    pub fn with_client(mut self, client: HttpClient) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Number of `search()` and `get_chapters()` calls started so far
+    pub fn call_count(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+
+    /// Counts a call and waits out the configured delay
+    async fn begin_call(&self) -> (usize, InFlight) {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst);
+        let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(now, Ordering::SeqCst);
+        let guard = InFlight(self.in_flight.clone());
+        tokio::time::sleep(self.delay).await;
+        (call, guard)
+    }
+}
+
+#[async_trait]
+impl Source for MockSource {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    fn name(&self) -> &'static str {
+        "Mock Source"
+    }
+
+    fn base_url(&self) -> &str {
+        "http://localhost"
+    }
+
+    fn capabilities(&self) -> &'static [Capability] {
+        self.capabilities
+    }
+
+    async fn search(&self, params: SearchParams) -> tosho::Result<Vec<Manga>> {
+        let (call, _guard) = self.begin_call().await;
+        if call < self.failures {
+            return Err((self.error)());
+        }
+        match &self.search_fn {
+            Some(search) => Ok(search(&params)),
+            None => Ok(self.manga.clone()),
+        }
+    }
+
+    async fn get_chapters(&self, manga_id: &str) -> tosho::Result<Vec<Chapter>> {
+        let (_, _guard) = self.begin_call().await;
+        let mut chapters = self.chapters.clone();
+        for chapter in &mut chapters {
+            chapter.manga_id = manga_id.to_string();
+        }
+        Ok(chapters)
+    }
+
+    async fn get_pages(&self, _chapter_id: &str) -> tosho::Result<Vec<String>> {
+        Ok(self.pages.clone())
+    }
+
+    fn http_client(&self) -> Option<&HttpClient> {
+        self.client.as_ref()
+    }
+}
+
+/// Builds a manga with only the required fields filled in
+#[allow(dead_code)]
+pub fn test_manga(id: &str, title: &str) -> Manga {
+    Manga {
+        id: id.to_string(),
+        url: None,
+        title: title.to_string(),
+        authors: vec![],
+        description: None,
+        tags: vec![],
+        content_rating: None,
+        available_languages: vec![],
+        cover_url: None,
+        source_id: "mock".to_string(),
+    }
+}
+
+/// Builds a chapter with only the required fields filled in
+#[allow(dead_code)]
+pub fn test_chapter(id: &str, number: f64) -> Chapter {
+    Chapter {
+        id: id.to_string(),
+        number,
+        volume: None,
+        title: format!("Chapter {}", number),
+        pages: vec![],
+        page_count: None,
+        manga_id: "test-manga".to_string(),
+        source_id: "mock".to_string(),
+    }
+}
+
+/// Directory holding captured pages for offline parsing tests
+#[allow(dead_code)]
+pub const FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+/// Reads a fixture file from [`FIXTURES_DIR`]
+/// Panics if the fixture is missing, since that is a broken test rather than a parse failure
+#[allow(dead_code)]
+pub fn load_fixture(name: &str) -> String {
+    let path = PathBuf::from(FIXTURES_DIR).join(name);
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read fixture {}: {}", path.display(), e))
+}
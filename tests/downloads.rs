@@ -4,11 +4,15 @@
 
 use std::path::PathBuf;
 use tokio::time::timeout;
+use tokio_util::sync::CancellationToken;
 use tosho::prelude::*;
 
 // Import test utilities from mod
 mod common;
-use common::{TEST_TIMEOUT, setup_test_dir};
+use common::{
+    MockSource, TEST_TIMEOUT, TestResponse, setup_test_dir, spawn_test_server, test_chapter,
+    test_manga,
+};
 
 #[cfg(test)]
 mod download_tests {
@@ -304,4 +308,415 @@ mod download_tests {
             Err(e) => println!("First download failed: {}", e),
         }
     }
+
+    #[tokio::test]
+    async fn test_download_chapter_cancellation() {
+        let test_dir = setup_test_dir().await.join("unit").join("cancel");
+        let _ = tokio::fs::remove_dir_all(&test_dir).await;
+
+        // Cancel as soon as the second page is requested
+        let cancel = CancellationToken::new();
+        let server_cancel = cancel.clone();
+        let server = spawn_test_server(move |index, _path| {
+            if index == 1 {
+                server_cancel.cancel();
+            }
+            TestResponse::ok(vec![0u8; 16])
+        });
+
+        let pages = (1..=3).map(|i| server.url(&format!("{}.jpg", i))).collect();
+        let source = MockSource::with_pages(pages);
+        let manga = test_manga("cancel", "Cancel Test");
+        let chapter = test_chapter("ch1", 1.0);
+
+        let result = source
+            .download_chapter_cancellable(&manga, &chapter, &test_dir, None, &cancel)
+            .await;
+
+        match result {
+            Err(tosho::Error::Other(msg)) => assert_eq!(msg, "cancelled"),
+            other => panic!("Expected cancellation error, got {:?}", other),
+        }
+
+        // Only the first page made it to disk, and the third was never requested
+        let chapter_dir = test_dir.join(manga.slug()).join("000-001");
+        assert!(chapter_dir.join("001.jpg").exists());
+        assert!(!chapter_dir.join("002.jpg").exists());
+        assert!(!chapter_dir.join("003.jpg").exists());
+        assert_eq!(server.request_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_download_chapter_honors_retry_after() {
+        let test_dir = setup_test_dir().await.join("unit").join("retry_after");
+        let _ = tokio::fs::remove_dir_all(&test_dir).await;
+
+        // The image host rate limits the first request
+        let server = spawn_test_server(|index, _path| {
+            if index == 0 {
+                TestResponse {
+                    status: 429,
+                    headers: vec![("Retry-After".to_string(), "1".to_string())],
+                    body: vec![],
+                }
+            } else {
+                TestResponse::ok(vec![0u8; 16])
+            }
+        });
+
+        let source = MockSource::with_pages(vec![server.url("1.jpg")]);
+        let manga = test_manga("retry", "Retry Test");
+        let chapter = test_chapter("ch1", 1.0);
+
+        let started = std::time::Instant::now();
+        let chapter_dir = source
+            .download_chapter(&manga, &chapter, &test_dir, None)
+            .await
+            .expect("download should succeed after the retry");
+
+        assert!(chapter_dir.join("001.jpg").exists());
+        assert_eq!(server.request_count(), 2);
+        assert!(started.elapsed() >= std::time::Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_download_chapter_uses_source_client() {
+        let test_dir = setup_test_dir().await.join("unit").join("source_client");
+        let _ = tokio::fs::remove_dir_all(&test_dir).await;
+
+        let server = spawn_test_server(|_, _| TestResponse::ok(vec![0u8; 16]));
+        let client = tosho::net::HttpClient::new("mock")
+            .with_rate_limit(60_000)
+            .with_header("X-Session", "abc");
+        let source = MockSource::with_pages(vec![server.url("1.jpg"), server.url("2.jpg")])
+            .with_client(client);
+        let manga = test_manga("client", "Client Test");
+        let chapter = test_chapter("ch1", 1.0);
+
+        // The source's rate limit would stall the second page for a minute
+        let chapter_dir = timeout(
+            TEST_TIMEOUT,
+            source.download_chapter(&manga, &chapter, &test_dir, None),
+        )
+        .await
+        .expect("page fetches should not be rate limited")
+        .unwrap();
+
+        assert!(chapter_dir.join("002.jpg").exists());
+        assert_eq!(
+            server.request_header(0, "x-session").as_deref(),
+            Some("abc")
+        );
+        assert_eq!(
+            server.request_header(1, "x-session").as_deref(),
+            Some("abc")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_covers() {
+        let test_dir = setup_test_dir().await.join("unit").join("covers");
+        let _ = tokio::fs::remove_dir_all(&test_dir).await;
+
+        let server = spawn_test_server(|_, _| TestResponse::ok(vec![0xFFu8, 0xD8, 0xFF]));
+
+        let mut first = test_manga("series/one", "First");
+        first.cover_url = Some(server.url("covers/one.png"));
+        let mut second = test_manga("two", "Second");
+        second.cover_url = Some(server.url("covers/two.jpg?size=512"));
+        let third = test_manga("three", "No Cover");
+        let mut manga = vec![first, second, third];
+
+        // Covers are fetched with the headers of the manga's source
+        let mut sources = Sources::new();
+        sources.add(
+            MockSource::with_pages(vec![]).with_client(
+                tosho::net::HttpClient::new("mock")
+                    .with_rate_limit(0)
+                    .with_header("Referer", "http://localhost/"),
+            ),
+        );
+        let results = sources
+            .prefetch_covers(&mut manga, &test_dir, 2, true)
+            .await;
+
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|(_, r)| r.is_ok()));
+        assert!(test_dir.join("mock-series_one.png").exists());
+        assert!(test_dir.join("mock-two.jpg").exists());
+        assert_eq!(server.request_count(), 2);
+        for index in [0, 1] {
+            assert_eq!(
+                server.request_header(index, "referer").as_deref(),
+                Some("http://localhost/")
+            );
+        }
+
+        // Cover URLs now point at the local files; manga without covers are untouched
+        let local = test_dir.join("mock-two.jpg");
+        assert_eq!(
+            manga[1].cover_url.as_deref(),
+            Some(local.to_string_lossy().as_ref())
+        );
+        assert!(manga[2].cover_url.is_none());
+
+        // Cached covers are not downloaded again
+        let mut again = vec![test_manga("two", "Second")];
+        again[0].cover_url = Some(server.url("covers/two.jpg"));
+        let results = sources
+            .prefetch_covers(&mut again, &test_dir, 2, false)
+            .await;
+        assert!(results[0].1.is_ok());
+        assert_eq!(server.request_count(), 2);
+        assert_eq!(
+            again[0].cover_url.as_deref(),
+            Some(server.url("covers/two.jpg").as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_download_chapter_naming_strategies() {
+        let server = spawn_test_server(|_, _| TestResponse::ok(vec![0u8; 16]));
+        let pages = vec![
+            server.url("data/x1-abc.png"),
+            server.url("data/x2-def.jpg?token=1"),
+        ];
+        let source = MockSource::with_pages(pages);
+        let manga = test_manga("naming", "Naming Test");
+        let chapter = test_chapter("ch1", 1.0);
+
+        let cases = [
+            (NamingStrategy::Indexed, ["1.png", "2.jpg"]),
+            (NamingStrategy::ZeroPadded(3), ["001.png", "002.jpg"]),
+            (NamingStrategy::ZeroPadded(5), ["00001.png", "00002.jpg"]),
+            (
+                NamingStrategy::PreserveOriginal,
+                ["x1-abc.png", "x2-def.jpg"],
+            ),
+        ];
+
+        for (i, (strategy, expected)) in cases.into_iter().enumerate() {
+            let test_dir = setup_test_dir()
+                .await
+                .join("unit")
+                .join("naming")
+                .join(i.to_string());
+            let _ = tokio::fs::remove_dir_all(&test_dir).await;
+
+            let chapter_dir = source
+                .download_chapter_named(&manga, &chapter, &test_dir, None, strategy)
+                .await
+                .expect("download should succeed");
+
+            let mut files: Vec<String> = std::fs::read_dir(&chapter_dir)
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+                .collect();
+            files.sort();
+            assert_eq!(files, expected, "unexpected file names for {:?}", strategy);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_preserve_original_keeps_pages_with_colliding_basenames() {
+        let test_dir = setup_test_dir()
+            .await
+            .join("unit")
+            .join("naming_collisions");
+        let _ = tokio::fs::remove_dir_all(&test_dir).await;
+
+        // The body is the path, so overwritten pages are easy to spot
+        let server = spawn_test_server(|_, path| TestResponse::ok(path));
+        let pages = vec![
+            server.url("a/1.jpg"),
+            server.url("b/1.jpg"),
+            server.url("c/2.jpg"),
+        ];
+        let source = MockSource::with_pages(pages);
+        let manga = test_manga("collisions", "Collision Test");
+        let chapter = test_chapter("ch1", 1.0);
+
+        let chapter_dir = source
+            .download_chapter_named(
+                &manga,
+                &chapter,
+                &test_dir,
+                None,
+                NamingStrategy::PreserveOriginal,
+            )
+            .await
+            .expect("download should succeed");
+
+        let read = |name: &str| std::fs::read_to_string(chapter_dir.join(name)).unwrap();
+        assert_eq!(read("1.jpg"), "/a/1.jpg");
+        assert_eq!(read("002-1.jpg"), "/b/1.jpg");
+        assert_eq!(read("2.jpg"), "/c/2.jpg");
+        assert_eq!(std::fs::read_dir(&chapter_dir).unwrap().count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_download_chapter_writes_manga_metadata() {
+        let test_dir = setup_test_dir().await.join("unit").join("metadata");
+        let _ = tokio::fs::remove_dir_all(&test_dir).await;
+
+        let server = spawn_test_server(|_, _| TestResponse::ok(vec![0u8; 16]));
+        let source = MockSource::with_pages(vec![server.url("1.jpg")]);
+        let manga = test_manga("meta", "Metadata: Test?");
+        let chapter = test_chapter("ch1", 1.0);
+
+        let chapter_dir = source
+            .download_chapter(&manga, &chapter, &test_dir, None)
+            .await
+            .expect("download should succeed");
+
+        let manga_dir = test_dir.join(manga.slug());
+        assert_eq!(chapter_dir, manga_dir.join("000-001"));
+
+        let saved = tosho::download::read_manga_metadata(&manga_dir)
+            .await
+            .unwrap();
+        assert_eq!(saved.title, "Metadata: Test?");
+        assert_eq!(saved.id, "meta");
+    }
+
+    #[tokio::test]
+    async fn test_manga_metadata_round_trip() {
+        use tosho::download::{MANGA_METADATA_FILE, read_manga_metadata, write_manga_metadata};
+
+        let test_dir = setup_test_dir().await.join("unit").join("sidecar");
+        let _ = tokio::fs::remove_dir_all(&test_dir).await;
+
+        let mut manga = test_manga("round-trip", "Round Trip");
+        manga.authors = vec!["Author".to_string()];
+        manga.tags = vec!["Action".to_string(), "Drama".to_string()];
+        manga.description = Some("A description".to_string());
+
+        // Nothing written yet
+        assert!(matches!(
+            read_manga_metadata(&test_dir).await,
+            Err(tosho::Error::NotFound(_))
+        ));
+
+        let path = write_manga_metadata(&manga, &test_dir).await.unwrap();
+        assert_eq!(path, test_dir.join(MANGA_METADATA_FILE));
+
+        let restored = read_manga_metadata(&test_dir).await.unwrap();
+        assert_eq!(restored.id, manga.id);
+        assert_eq!(restored.title, manga.title);
+        assert_eq!(restored.authors, manga.authors);
+        assert_eq!(restored.tags, manga.tags);
+        assert_eq!(restored.description, manga.description);
+        assert_eq!(restored.source_id, manga.source_id);
+
+        // An unchanged manga leaves the sidecar alone
+        let old = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000);
+        let modified = || std::fs::metadata(&path).unwrap().modified().unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(old)
+            .unwrap();
+        write_manga_metadata(&manga, &test_dir).await.unwrap();
+        assert_eq!(modified(), old);
+
+        manga.title = "Round Trip (Retitled)".to_string();
+        write_manga_metadata(&manga, &test_dir).await.unwrap();
+        assert_ne!(modified(), old);
+        let restored = read_manga_metadata(&test_dir).await.unwrap();
+        assert_eq!(restored.title, manga.title);
+    }
+
+    #[tokio::test]
+    async fn test_chapter_download_detection() {
+        use tosho::download::{chapter_dir, chapter_is_downloaded, downloaded_chapters};
+
+        let test_dir = setup_test_dir().await.join("unit").join("detection");
+        let _ = tokio::fs::remove_dir_all(&test_dir).await;
+
+        let manga = test_manga("detect", "Detection: Test");
+        let downloaded = test_chapter("ch1", 1.0);
+        let empty = test_chapter("ch2", 2.0);
+        let missing = test_chapter("ch3", 2.5);
+
+        let downloaded_dir = chapter_dir(&test_dir, &manga, &downloaded);
+        assert_eq!(downloaded_dir, test_dir.join(manga.slug()).join("000-001"));
+        std::fs::create_dir_all(&downloaded_dir).unwrap();
+        std::fs::write(downloaded_dir.join("001.jpg"), [0u8; 4]).unwrap();
+
+        // A directory without page files doesn't count
+        let empty_dir = chapter_dir(&test_dir, &manga, &empty);
+        std::fs::create_dir_all(empty_dir.join("nested")).unwrap();
+        std::fs::write(empty_dir.join(".DS_Store"), [0u8; 4]).unwrap();
+        std::fs::write(empty_dir.join("metadata.json"), "{}").unwrap();
+        std::fs::write(empty_dir.join("001.jpg.part"), [0u8; 4]).unwrap();
+
+        assert!(chapter_is_downloaded(&test_dir, &manga, &downloaded));
+        assert!(!chapter_is_downloaded(&test_dir, &manga, &empty));
+        assert!(!chapter_is_downloaded(&test_dir, &manga, &missing));
+        assert_eq!(
+            downloaded_chapters(&test_dir, &manga, &[downloaded.clone(), empty, missing]),
+            vec![true, false, false]
+        );
+
+        // With a known page count, a partly downloaded chapter doesn't count
+        let mut partial = downloaded.clone();
+        partial.page_count = Some(2);
+        assert!(!chapter_is_downloaded(&test_dir, &manga, &partial));
+        std::fs::write(downloaded_dir.join("002.jpg"), [0u8; 4]).unwrap();
+        assert!(chapter_is_downloaded(&test_dir, &manga, &partial));
+
+        let mut listed = downloaded;
+        listed.pages = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        assert!(!chapter_is_downloaded(&test_dir, &manga, &listed));
+    }
+
+    #[cfg(feature = "conversion")]
+    #[tokio::test]
+    async fn test_download_chapter_validated_flags_tiny_pages() {
+        use tosho::download::MIN_PAGE_DIMENSION;
+
+        let png = |size: u32| {
+            let mut data = std::io::Cursor::new(Vec::new());
+            image::RgbImage::new(size, size)
+                .write_to(&mut data, image::ImageFormat::Png)
+                .unwrap();
+            data.into_inner()
+        };
+        let page = png(64);
+        let pixel = png(1);
+        let server = spawn_test_server(move |_, path| match path {
+            "/pixel.png" => TestResponse::ok(pixel.clone()),
+            "/error.png" => TestResponse::ok("<html>Not found</html>"),
+            _ => TestResponse::ok(page.clone()),
+        });
+
+        let source = MockSource::with_pages(vec![
+            server.url("page.png"),
+            server.url("pixel.png"),
+            server.url("error.png"),
+        ]);
+        let manga = test_manga("validated", "Validation Test");
+        let chapter = test_chapter("ch1", 1.0);
+
+        let test_dir = setup_test_dir().await.join("unit").join("validated");
+        let _ = tokio::fs::remove_dir_all(&test_dir).await;
+
+        let report = source
+            .download_chapter_validated(&manga, &chapter, &test_dir, None, MIN_PAGE_DIMENSION)
+            .await
+            .expect("download should succeed");
+
+        assert_eq!(report.saved_pages, 1);
+        let flagged: Vec<usize> = report.invalid_pages.iter().map(|p| p.page).collect();
+        assert_eq!(flagged, [2, 3]);
+        assert!(report.invalid_pages[0].reason.contains("1x1"));
+        assert!(report.invalid_pages[1].url.ends_with("error.png"));
+
+        let files: Vec<String> = std::fs::read_dir(&report.chapter_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(files, ["001.png"]);
+    }
 }
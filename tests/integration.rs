@@ -32,6 +32,17 @@ mod integration_tests {
         assert!(source_ids.contains(&"mgd"));
     }
 
+    #[cfg(feature = "all-sources")]
+    #[test]
+    fn test_sources_with_all_enabled() {
+        let sources = Sources::with_all_enabled();
+
+        let source_ids = sources.list_ids();
+        assert!(source_ids.contains(&"mgd"));
+        assert!(source_ids.contains(&"kmg"));
+        assert_eq!(sources.len(), 2);
+    }
+
     #[tokio::test]
     async fn test_sources_fluent_api() {
         let mut sources = Sources::new();
@@ -104,8 +115,8 @@ mod integration_tests {
                         let download_result = timeout(
                             Duration::from_secs(60),
                             source.download_chapter(
-                                &manga,
-                                &chapter,
+                                manga,
+                                chapter,
                                 &test_dir,
                                 Some(ImageFormat::Jpeg),
                             ),
@@ -0,0 +1,35 @@
+//! Global request counter tests
+//!
+//! Kept in their own test binary: the counter is process-wide, so requests
+//! made by tests running in parallel would skew the numbers.
+
+use tosho::net::{self, HttpClient};
+
+// Import test utilities from mod
+mod common;
+use common::{TestResponse, spawn_test_server};
+
+#[cfg(test)]
+mod request_counter_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_total_requests_counts_every_request() {
+        let server = spawn_test_server(|_, _| TestResponse::ok("ok"));
+        let first = HttpClient::new("first").with_rate_limit(0);
+        let second = HttpClient::new("second").with_rate_limit(0);
+
+        net::reset_request_counter();
+        assert_eq!(net::total_requests(), 0);
+
+        for i in 0..5 {
+            let client = if i % 2 == 0 { &first } else { &second };
+            client.get(&server.url("/page")).await.unwrap();
+        }
+        assert_eq!(net::total_requests(), 5);
+        assert_eq!(server.request_count(), 5);
+
+        net::reset_request_counter();
+        assert_eq!(net::total_requests(), 0);
+    }
+}
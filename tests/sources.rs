@@ -9,12 +9,705 @@ use tosho::types::ImageFormat;
 
 // Import test utilities
 mod common;
-use common::{DOWNLOAD_TIMEOUT, TEST_TIMEOUT, setup_test_dir};
+use common::{
+    DOWNLOAD_TIMEOUT, TEST_TIMEOUT, TestResponse, load_fixture, setup_test_dir, spawn_test_server,
+};
 
 #[cfg(test)]
 mod source_tests {
     use super::*;
 
+    #[test]
+    fn test_build_source_by_id() {
+        let mangadex = tosho::sources::build_by_id("mgd").expect("mgd should be available");
+        assert_eq!(mangadex.id(), "mgd");
+
+        let kissmanga = tosho::sources::build_by_id("kmg").expect("kmg should be available");
+        assert_eq!(kissmanga.id(), "kmg");
+
+        assert!(tosho::sources::build_by_id("unknown").is_none());
+        assert!(tosho::sources::build_by_id("").is_none());
+    }
+
+    #[test]
+    fn test_source_rate_limit_override() {
+        let mut sources = Sources::new();
+        sources.add(MangaDexSource::new());
+        sources.add(KissMangaSource::new());
+        sources.add(common::MockSource::with_pages(vec![]));
+
+        assert_eq!(sources.get("mgd").unwrap().rate_limit_ms(), Some(1000));
+        assert_eq!(sources.get("kmg").unwrap().rate_limit_ms(), Some(2000));
+
+        sources.set_rate_limit("mgd", 2500).unwrap();
+        assert_eq!(sources.get("mgd").unwrap().rate_limit_ms(), Some(2500));
+        // Other sources keep their own delay
+        assert_eq!(sources.get("kmg").unwrap().rate_limit_ms(), Some(2000));
+
+        // Sources without an HTTP client have no rate limit to change
+        assert_eq!(sources.get("mock").unwrap().rate_limit_ms(), None);
+        assert!(sources.set_rate_limit("mock", 100).is_err());
+        assert!(matches!(
+            sources.set_rate_limit("missing", 100),
+            Err(tosho::Error::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_mangadex_builder_custom_api_base() {
+        use std::sync::{Arc, Mutex};
+
+        let paths = Arc::new(Mutex::new(Vec::new()));
+        let log = paths.clone();
+        let server = spawn_test_server(move |_, path| {
+            log.lock().unwrap().push(path.to_string());
+            TestResponse::ok(r#"{"data":[],"total":0,"limit":20,"offset":0}"#)
+        });
+
+        let source = MangaDexSource::builder()
+            .api_base(server.url("/api/"))
+            .rate_limit_ms(0)
+            .max_retries(0)
+            .preferred_languages(vec!["es-la".to_string()])
+            .build();
+
+        let results = source.search(SearchParams::from("naruto")).await.unwrap();
+        assert!(results.is_empty());
+        let chapters = source.get_chapters("manga-1").await.unwrap();
+        assert!(chapters.is_empty());
+
+        let paths = paths.lock().unwrap();
+        assert_eq!(server.request_count(), 2);
+        assert!(paths[0].starts_with("/api/manga?title=naruto&"));
+        assert!(paths[1].starts_with("/api/manga/manga-1/feed?"));
+        assert!(paths[1].contains("translatedLanguage%5B%5D=es-la"));
+        assert!(!paths[1].contains("translatedLanguage%5B%5D=en"));
+    }
+
+    #[tokio::test]
+    async fn test_mangadex_error_body_with_ok_status() {
+        let server = spawn_test_server(|_, _| {
+            TestResponse::ok(
+                r#"{"result":"error","errors":[{"id":"x","status":400,"title":"validation_exception","detail":"Error validating /limit"}]}"#,
+            )
+        });
+
+        let source = MangaDexSource::builder()
+            .api_base(server.url(""))
+            .rate_limit_ms(0)
+            .max_retries(0)
+            .build();
+
+        let err = source
+            .search(SearchParams::from("naruto"))
+            .await
+            .unwrap_err();
+        match err {
+            tosho::Error::Source {
+                src,
+                message,
+                status,
+                ..
+            } => {
+                assert_eq!(src, "mgd");
+                assert_eq!(message, "Error validating /limit");
+                assert_eq!(status, Some(400));
+            }
+            other => panic!("expected source error, got {other:?}"),
+        }
+        assert_eq!(server.request_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mangadex_manga_batch_chunks_ids() {
+        // Echoes back one manga per requested ID
+        let server = spawn_test_server(|_, path| {
+            let data: Vec<String> = path
+                .split(['?', '&'])
+                .filter_map(|pair| pair.strip_prefix("ids%5B%5D="))
+                .map(|id| {
+                    format!(
+                        r#"{{"id":"{id}","type":"manga","attributes":{{"title":{{"en":"Manga {id}"}},"status":"ongoing"}},"relationships":[]}}"#
+                    )
+                })
+                .collect();
+            TestResponse::ok(format!(
+                r#"{{"data":[{}],"total":{},"limit":100,"offset":0}}"#,
+                data.join(","),
+                data.len()
+            ))
+        });
+
+        let source = MangaDexSource::builder()
+            .api_base(server.url(""))
+            .rate_limit_ms(0)
+            .max_retries(0)
+            .build();
+
+        let ids: Vec<String> = (0..150).map(|i| format!("id-{i}")).collect();
+        let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+        let manga = source.get_manga_batch(&ids).await.unwrap();
+
+        assert_eq!(server.request_count(), 2);
+        assert_eq!(manga.len(), 150);
+        assert_eq!(manga[0].id, "id-0");
+        assert_eq!(manga[149].title, "Manga id-149");
+        assert!(manga.iter().all(|m| m.source_id == "mgd"));
+    }
+
+    #[tokio::test]
+    async fn test_mangadex_browse_by_tag() {
+        use std::sync::{Arc, Mutex};
+
+        let paths = Arc::new(Mutex::new(Vec::new()));
+        let log = paths.clone();
+        let server = spawn_test_server(move |_, path| {
+            log.lock().unwrap().push(path.to_string());
+            if path.starts_with("/manga/tag") {
+                TestResponse::ok(
+                    r#"{"result":"ok","data":[
+                        {"id":"tag-action","type":"tag","attributes":{"name":{"en":"Action"}}},
+                        {"id":"tag-romance","type":"tag","attributes":{"name":{"en":"Romance"}}}
+                    ]}"#,
+                )
+            } else {
+                TestResponse::ok(
+                    r#"{"data":[{"id":"m1","type":"manga","attributes":{"title":{"en":"Fighter"},"status":"ongoing"},"relationships":[]}],"total":1,"limit":20,"offset":0}"#,
+                )
+            }
+        });
+
+        let mut sources = Sources::new();
+        sources.add(
+            MangaDexSource::builder()
+                .api_base(server.url(""))
+                .rate_limit_ms(0)
+                .max_retries(0)
+                .build(),
+        );
+
+        // Without any filter, browsing is refused before a request is made
+        assert!(sources.search("").browse().await.is_err());
+        assert_eq!(server.request_count(), 0);
+
+        let results = sources
+            .search("")
+            .include_tags(vec!["action".to_string()])
+            .require_capability(Capability::TagFiltering)
+            .browse()
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].title, "Fighter");
+
+        {
+            let paths = paths.lock().unwrap();
+            assert_eq!(paths[0], "/manga/tag");
+            assert!(paths[1].starts_with("/manga?"));
+            assert!(!paths[1].contains("title="));
+            assert!(paths[1].contains("includedTags%5B%5D=tag-action"));
+            assert!(!paths[1].contains("tag-romance"));
+        }
+
+        // The tag list is fetched once, and unknown tags fail instead of being dropped
+        let error = sources
+            .search("")
+            .include_tags(vec!["Nonexistent".to_string()])
+            .browse()
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("Nonexistent"));
+        assert_eq!(server.request_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_mangadex_download_refreshes_expired_page_urls() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::{Arc, Mutex};
+
+        let base_url = Arc::new(Mutex::new(String::new()));
+        let at_home_calls = Arc::new(AtomicUsize::new(0));
+        let paths = Arc::new(Mutex::new(Vec::new()));
+
+        let server = spawn_test_server({
+            let (base_url, at_home_calls, paths) =
+                (base_url.clone(), at_home_calls.clone(), paths.clone());
+            move |_, path| {
+                paths.lock().unwrap().push(path.to_string());
+                match path {
+                    "/chapter/ch-1" => TestResponse::ok(
+                        r#"{"result":"ok","data":{"id":"ch-1","attributes":{"title":null,"chapter":"1","volume":null,"publishAt":null,"translatedLanguage":"en"},"relationships":[]}}"#,
+                    ),
+                    "/at-home/server/ch-1" => {
+                        // Every call hands out a new token, like the real at-home API
+                        let token = at_home_calls.fetch_add(1, Ordering::SeqCst);
+                        TestResponse::ok(format!(
+                            r#"{{"result":"ok","baseUrl":"{}/token-{}","chapter":{{"hash":"abc","data":["1.png","2.png","3.png"],"dataSaver":[]}}}}"#,
+                            base_url.lock().unwrap(),
+                            token
+                        ))
+                    }
+                    // The first token expires after the first page
+                    "/token-0/data/abc/1.png" => TestResponse::ok("page"),
+                    p if p.starts_with("/token-0/") => TestResponse {
+                        status: 410,
+                        headers: vec![],
+                        body: Vec::new(),
+                    },
+                    _ => TestResponse::ok("page"),
+                }
+            }
+        });
+        *base_url.lock().unwrap() = server.url("").trim_end_matches('/').to_string();
+
+        let source = MangaDexSource::builder()
+            .api_base(server.url(""))
+            .rate_limit_ms(0)
+            .max_retries(0)
+            .build();
+        let manga = common::test_manga("expiring", "Expiring Pages");
+        let mut chapter = common::test_chapter("ch-1", 1.0);
+        chapter.manga_id = manga.id.clone();
+        let output_dir = setup_test_dir().await.join("sources/mangadex");
+
+        let chapter_dir = source
+            .download_chapter(&manga, &chapter, &output_dir, None)
+            .await
+            .unwrap();
+
+        for page in ["001.png", "002.png", "003.png"] {
+            assert!(chapter_dir.join(page).exists(), "{} missing", page);
+        }
+        assert_eq!(at_home_calls.load(Ordering::SeqCst), 2);
+
+        // Only the page that failed and the ones after it use the fresh token
+        let paths = paths.lock().unwrap();
+        let page_requests: Vec<&str> = paths
+            .iter()
+            .map(String::as_str)
+            .filter(|p| p.contains("/data/"))
+            .collect();
+        assert_eq!(
+            page_requests,
+            [
+                "/token-0/data/abc/1.png",
+                "/token-0/data/abc/2.png",
+                "/token-1/data/abc/2.png",
+                "/token-1/data/abc/3.png",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mangadex_pages_detailed_from_at_home_fixture() {
+        let server = spawn_test_server(|_, path| match path {
+            "/chapter/ch-1" => TestResponse::ok(
+                r#"{"result":"ok","data":{"id":"ch-1","attributes":{"title":null,"chapter":"1","volume":null,"publishAt":null,"translatedLanguage":"en"},"relationships":[]}}"#,
+            ),
+            "/at-home/server/ch-1" => TestResponse::ok(load_fixture("mangadex_at_home.json")),
+            _ => TestResponse {
+                status: 404,
+                headers: vec![],
+                body: Vec::new(),
+            },
+        });
+        let source = MangaDexSource::builder()
+            .api_base(server.url(""))
+            .rate_limit_ms(0)
+            .max_retries(0)
+            .build();
+
+        let pages = source.get_pages_detailed("ch-1").await.unwrap();
+        let urls: Vec<&str> = pages.iter().map(|p| p.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            [
+                "https://uploads.mangadex.org/data/3303dd03ac8d27452cce3f2a882e94b2/1-f7a76de10d346de7ba01786762ebbedc666b412ad0d4b73baa330a2a392dbcdd.png",
+                "https://uploads.mangadex.org/data/3303dd03ac8d27452cce3f2a882e94b2/2-2ad7ee3d7ebca9f3d9bd5e2d0dcc4a7b8cc0e5cf8b8b3b5a9a0fb8ef08c0b0e8.png",
+            ]
+        );
+        // The at-home API reports neither dimensions nor a referer
+        assert!(
+            pages
+                .iter()
+                .all(|p| p.width.is_none() && p.height.is_none() && p.referer.is_none())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_madara_pages_detailed_carry_referer() {
+        use tosho::sources::madara_configurable::{
+            ConfigurableMadaraSource, MadaraConfig, MadaraSelectors,
+        };
+
+        let server = spawn_test_server(|_, _| {
+            TestResponse::ok(
+                r#"<div class="reading-content">
+                    <div class="page-break"><img src="https://cdn.example.com/ch1/01.jpg"></div>
+                    <div class="page-break"><img src="https://cdn.example.com/ch1/02.jpg"></div>
+                </div>"#,
+            )
+        });
+        let base_url: &'static str = Box::leak(server.url("").into_boxed_str());
+        let source = ConfigurableMadaraSource::new(MadaraConfig {
+            id: "madara-test",
+            name: "Madara Test",
+            base_url,
+            headers: None,
+            selectors: MadaraSelectors {
+                manga_item: String::new(),
+                chapter_links: String::new(),
+                chapter_titles: String::new(),
+                chapter_pages: ".reading-content .page-break img".to_string(),
+                cover_image: String::new(),
+            },
+        });
+
+        let pages = source
+            .get_pages_detailed("manga/test/chapter-1")
+            .await
+            .unwrap();
+        let urls: Vec<&str> = pages.iter().map(|p| p.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            [
+                "https://cdn.example.com/ch1/01.jpg",
+                "https://cdn.example.com/ch1/02.jpg"
+            ]
+        );
+        let referer = server.url("manga/test/chapter-1");
+        assert!(
+            pages
+                .iter()
+                .all(|p| p.referer.as_deref() == Some(&*referer))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_madara_derives_referer_and_origin() {
+        use std::collections::HashMap;
+        use tosho::sources::madara_configurable::{
+            ConfigurableMadaraSource, MadaraConfig, MadaraSelectors,
+        };
+
+        let server = spawn_test_server(|_, _| TestResponse::ok("<html></html>"));
+        let base_url: &'static str = Box::leak(
+            server
+                .url("")
+                .trim_end_matches('/')
+                .to_string()
+                .into_boxed_str(),
+        );
+        let config = |headers| MadaraConfig {
+            id: "madara-test",
+            name: "Madara Test",
+            base_url,
+            headers,
+            selectors: MadaraSelectors {
+                manga_item: ".post-title a".to_string(),
+                chapter_links: String::new(),
+                chapter_titles: String::new(),
+                chapter_pages: String::new(),
+                cover_image: String::new(),
+            },
+        };
+
+        let source = ConfigurableMadaraSource::new(config(None));
+        source.search(SearchParams::from("naruto")).await.unwrap();
+        assert_eq!(
+            server.request_header(0, "referer"),
+            Some(format!("{}/", base_url))
+        );
+        assert_eq!(
+            server.request_header(0, "origin").as_deref(),
+            Some(base_url)
+        );
+
+        // Configured headers win over the derived ones
+        let overrides = HashMap::from([(
+            "referer".to_string(),
+            "https://mirror.example.com/".to_string(),
+        )]);
+        let source = ConfigurableMadaraSource::new(config(Some(overrides)));
+        source.search(SearchParams::from("naruto")).await.unwrap();
+        assert_eq!(
+            server.request_header(1, "Referer").as_deref(),
+            Some("https://mirror.example.com/")
+        );
+        assert_eq!(
+            server.request_header(1, "Origin").as_deref(),
+            Some(base_url)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_madara_download_sends_referer_to_image_host() {
+        use std::sync::{Arc, Mutex};
+        use tosho::sources::madara_configurable::{
+            ConfigurableMadaraSource, MadaraConfig, MadaraSelectors,
+        };
+
+        let base_url = Arc::new(Mutex::new(String::new()));
+        let server = spawn_test_server({
+            let base_url = base_url.clone();
+            move |_, path| match path {
+                "/manga/test/chapter-1" => TestResponse::ok(format!(
+                    r#"<div class="reading-content">
+                        <div class="page-break"><img src="{0}/uploads/01.jpg"></div>
+                        <div class="page-break"><img src="{0}/uploads/02.jpg"></div>
+                    </div>"#,
+                    base_url.lock().unwrap()
+                )),
+                _ => TestResponse::ok(vec![0u8; 16]),
+            }
+        });
+        let origin: &'static str = Box::leak(
+            server
+                .url("")
+                .trim_end_matches('/')
+                .to_string()
+                .into_boxed_str(),
+        );
+        *base_url.lock().unwrap() = origin.to_string();
+
+        let source = ConfigurableMadaraSource::new(MadaraConfig {
+            id: "madara-test",
+            name: "Madara Test",
+            base_url: origin,
+            headers: None,
+            selectors: MadaraSelectors {
+                manga_item: String::new(),
+                chapter_links: String::new(),
+                chapter_titles: String::new(),
+                chapter_pages: ".reading-content .page-break img".to_string(),
+                cover_image: String::new(),
+            },
+        });
+        let manga = common::test_manga("madara-referer", "Madara Referer");
+        let chapter = common::test_chapter("manga/test/chapter-1", 1.0);
+        let output_dir = setup_test_dir().await.join("sources/madara");
+
+        let chapter_dir = source
+            .download_chapter(&manga, &chapter, &output_dir, None)
+            .await
+            .unwrap();
+        assert!(chapter_dir.join("002.jpg").exists());
+
+        // Requests 1 and 2 are the images, sent as if loaded by the reader page
+        assert_eq!(server.request_count(), 3);
+        let reader_url = server.url("manga/test/chapter-1");
+        for index in [1, 2] {
+            assert_eq!(
+                server.request_header(index, "referer").as_deref(),
+                Some(&*reader_url)
+            );
+            assert_eq!(
+                server.request_header(index, "origin").as_deref(),
+                Some(origin)
+            );
+        }
+    }
+
+    #[test]
+    fn test_kissmanga_parses_search_fixture() {
+        let source = KissMangaSource::new();
+        let manga = source.parse_manga_list(&load_fixture("kissmanga_search.html"));
+
+        let ids: Vec<&str> = manga.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            [
+                "kissmanga/naruto",
+                "kissmanga/boruto-naruto-next-generations"
+            ]
+        );
+        assert_eq!(manga[1].title, "Boruto: Naruto Next Generations");
+        assert_eq!(
+            manga[0].url.as_deref(),
+            Some("https://kissmanga.in/kissmanga/naruto/")
+        );
+        assert_eq!(
+            manga[0].cover_url.as_deref(),
+            Some("https://kissmanga.in/wp-content/uploads/2020/05/naruto-193x278.jpg")
+        );
+        assert!(manga.iter().all(|m| m.source_id == "kmg"));
+    }
+
+    #[test]
+    fn test_madara_parses_chapter_fixture() {
+        use tosho::sources::madara_configurable::{
+            ConfigurableMadaraSource, MadaraConfig, MadaraSelectors,
+        };
+
+        let source = ConfigurableMadaraSource::new(MadaraConfig {
+            id: "madara-test",
+            name: "Madara Test",
+            base_url: "https://madara.example.com",
+            headers: None,
+            selectors: MadaraSelectors {
+                manga_item: ".post-title h3 a".to_string(),
+                chapter_links: ".wp-manga-chapter a".to_string(),
+                chapter_titles: ".wp-manga-chapter a".to_string(),
+                chapter_pages: ".reading-content .page-break img".to_string(),
+                cover_image: String::new(),
+            },
+        });
+        let html = load_fixture("madara_chapter.html");
+
+        // Loading placeholders and ads are filtered out
+        let pages = source.parse_pages(&html);
+        assert_eq!(
+            pages,
+            [
+                "https://cdn.madara.example.com/manga/test/ch1/01.jpg",
+                "https://cdn.madara.example.com/manga/test/ch1/02.webp",
+                "https://cdn.madara.example.com/manga/test/ch1/03.png",
+            ]
+        );
+
+        let chapters = source.parse_chapter_list(&html, "manga/test");
+        let titles: Vec<&str> = chapters.iter().map(|c| c.title.as_str()).collect();
+        // The site lists chapters newest first; they come back in reading order
+        assert_eq!(titles, ["Chapter 1", "Chapter 2 - The Return"]);
+        let numbers: Vec<f64> = chapters.iter().map(|c| c.number).collect();
+        assert_eq!(numbers, [1.0, 2.0]);
+        assert_eq!(
+            chapters[0].id,
+            "https://madara.example.com/manga/test/chapter-1"
+        );
+        assert!(chapters.iter().all(|c| c.manga_id == "manga/test"));
+
+        assert!(source.parse_pages("<html></html>").is_empty());
+    }
+
+    #[test]
+    fn test_madara_numbers_untitled_chapters() {
+        use tosho::sources::madara_configurable::{
+            ConfigurableMadaraSource, MadaraConfig, MadaraSelectors,
+        };
+
+        let source = ConfigurableMadaraSource::new(MadaraConfig {
+            id: "madara-test",
+            name: "Madara Test",
+            base_url: "https://madara.example.com",
+            headers: None,
+            selectors: MadaraSelectors {
+                manga_item: String::new(),
+                chapter_links: ".wp-manga-chapter a".to_string(),
+                chapter_titles: ".wp-manga-chapter a".to_string(),
+                chapter_pages: String::new(),
+                cover_image: String::new(),
+            },
+        });
+        let list = |titles: &[&str]| -> String {
+            titles
+                .iter()
+                .enumerate()
+                .map(|(i, title)| {
+                    format!(
+                        r#"<li class="wp-manga-chapter"><a href="/c/{}/">{}</a></li>"#,
+                        i, title
+                    )
+                })
+                .collect()
+        };
+        let numbers = |html: &str| -> Vec<(String, f64)> {
+            source
+                .parse_chapter_list(html, "manga/test")
+                .into_iter()
+                .map(|c| (c.title, c.number))
+                .collect()
+        };
+
+        // Extras don't take the number of a real chapter
+        let parsed = numbers(&list(&["Chapter 2", "Extra", "Chapter 1"]));
+        assert_eq!(
+            parsed,
+            [
+                ("Extra".to_string(), 0.0),
+                ("Chapter 1".to_string(), 1.0),
+                ("Chapter 2".to_string(), 2.0),
+            ]
+        );
+
+        // Without any numbered title, the oldest chapter is number 1
+        let parsed = numbers(&list(&["Finale", "Beginning"]));
+        assert_eq!(
+            parsed,
+            [("Beginning".to_string(), 1.0), ("Finale".to_string(), 2.0)]
+        );
+    }
+
+    #[test]
+    fn test_madara_parse_manga_list_resolves_urls() {
+        use tosho::sources::madara_configurable::{
+            ConfigurableMadaraSource, MadaraConfig, MadaraSelectors,
+        };
+
+        let source = ConfigurableMadaraSource::new(MadaraConfig {
+            id: "madara-test",
+            name: "Madara Test",
+            base_url: "https://madara.example.com",
+            headers: None,
+            selectors: MadaraSelectors {
+                manga_item: ".post-title a".to_string(),
+                chapter_links: String::new(),
+                chapter_titles: String::new(),
+                chapter_pages: String::new(),
+                cover_image: ".tab-thumb img".to_string(),
+            },
+        });
+
+        let manga = source.parse_manga_list(
+            r#"
+            <div class="tab-thumb"><img src="/covers/one.jpg"></div>
+            <div class="post-title"><a href="/manga/one/">One</a></div>
+            <div class="tab-thumb"><img src="https://cdn.example.com/two.jpg"></div>
+            <div class="post-title"><a href="https://madara.example.com/manga/two/">Two</a></div>
+            <div class="post-title"><a href="/manga/untitled/">  </a></div>
+            "#,
+        );
+
+        let ids: Vec<&str> = manga.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["manga/one", "manga/two"]);
+        assert_eq!(
+            manga[0].url.as_deref(),
+            Some("https://madara.example.com/manga/one/")
+        );
+        assert_eq!(
+            manga[0].cover_url.as_deref(),
+            Some("https://madara.example.com/covers/one.jpg")
+        );
+        assert_eq!(
+            manga[1].cover_url.as_deref(),
+            Some("https://cdn.example.com/two.jpg")
+        );
+        assert!(source.parse_manga_list("<html></html>").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_madara_rejects_short_queries() {
+        let source = KissMangaSource::new();
+        assert_eq!(source.min_query_length(), 3);
+        assert_eq!(MangaDexSource::new().min_query_length(), 0);
+
+        // Rejected before any request is made
+        let error = source.search(SearchParams::from(" ab ")).await.unwrap_err();
+        assert!(matches!(&error, tosho::Error::Parse(msg) if msg.contains("too short")));
+
+        // Through Sources, the guard is reported as that source's error
+        let mut sources = Sources::new();
+        sources.add(source);
+        let mut mock = common::MockSource::with_pages(vec![]);
+        mock.manga = vec![common::test_manga("1", "Ab")];
+        sources.add(mock);
+
+        let grouped = sources.search_all_grouped(SearchParams::from("ab")).await;
+        assert_eq!(grouped.len(), 2);
+        assert!(matches!(&grouped[0], (id, Err(tosho::Error::Parse(_))) if id == "kmg"));
+        assert!(matches!(&grouped[1], (id, Ok(manga)) if id == "mock" && manga.len() == 1));
+    }
+
     #[tokio::test]
     async fn test_mangadex_basic_functionality() {
         let source = MangaDexSource::new();
@@ -124,8 +817,8 @@ mod source_tests {
 
                         // Test the download
                         let download_future = source.download_chapter(
-                            &manga,
-                            &chapter,
+                            manga,
+                            chapter,
                             &test_dir,
                             Some(ImageFormat::Jpeg),
                         );
@@ -198,8 +891,8 @@ mod source_tests {
 
                         // Test the download with KissManga's custom implementation
                         let download_future = source.download_chapter(
-                            &manga,
-                            &chapter,
+                            manga,
+                            chapter,
                             &test_dir,
                             Some(ImageFormat::Jpeg),
                         );
@@ -5,6 +5,9 @@
 use tosho::prelude::*;
 use tosho::types::SearchParamsBuilder;
 
+mod common;
+use common::{TestResponse, spawn_test_server};
+
 #[cfg(test)]
 mod unit_tests {
     use super::*;
@@ -37,6 +40,8 @@ mod unit_tests {
             authors: vec!["Author 1".to_string(), "Author 2".to_string()],
             description: Some("A test manga description".to_string()),
             tags: vec!["Action".to_string(), "Adventure".to_string()],
+            content_rating: None,
+            available_languages: vec![],
             cover_url: Some("https://example.com/cover.jpg".to_string()),
             source_id: "test".to_string(),
         };
@@ -61,6 +66,7 @@ mod unit_tests {
                 "https://example.com/page1.jpg".to_string(),
                 "https://example.com/page2.jpg".to_string(),
             ],
+            page_count: None,
             manga_id: "test-manga".to_string(),
             source_id: "test".to_string(),
         };
@@ -136,6 +142,48 @@ mod unit_tests {
         assert!(ids.contains(&"mgd"));
     }
 
+    #[tokio::test]
+    async fn test_sources_from_iterator() {
+        let boxed: Vec<Box<dyn Source>> = vec![
+            Box::new(tosho::sources::MangaDexSource::new()),
+            Box::new(common::MockSource::with_pages(vec!["first".to_string()])),
+            Box::new(common::MockSource::with_pages(vec!["second".to_string()])),
+        ];
+
+        let sources: Sources = boxed.into_iter().collect();
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources.list_ids(), ["mgd", "mock"]);
+        assert_eq!(sources.get("mgd").unwrap().name(), "MangaDex");
+        assert_eq!(sources.get("mock").unwrap().name(), "Mock Source");
+
+        // The first source with a duplicate ID wins
+        let pages = sources.get("mock").unwrap().get_pages("ch").await.unwrap();
+        assert_eq!(pages, ["first"]);
+    }
+
+    #[tokio::test]
+    async fn test_source_lookup_ignores_case() {
+        let mut sources = Sources::new();
+        sources.add(tosho::sources::MangaDexSource::new());
+
+        for id in ["mgd", "MGD", "Mgd"] {
+            let source = sources.get(id).expect("lookup should ignore case");
+            assert_eq!(source.id(), "mgd");
+        }
+        assert!(sources.get_or_err("MGD").is_ok());
+        assert!(sources.get("mangadex").is_none());
+
+        // IDs are still reported as the sources define them
+        assert_eq!(sources.list_ids(), ["mgd"]);
+
+        let mut mixed = Sources::new();
+        let mut source = common::MockSource::with_pages(vec![]);
+        source.manga = vec![common::test_manga("1", "One")];
+        mixed.add(source);
+        let results = mixed.search("one").from_source("MOCK").await.unwrap();
+        assert_eq!(results.len(), 1);
+    }
+
     #[test]
     fn test_manga_list_extensions() {
         let manga_list = vec![
@@ -146,6 +194,8 @@ mod unit_tests {
                 authors: vec!["Oda".to_string()],
                 description: None,
                 tags: vec!["Action".to_string()],
+                content_rating: None,
+                available_languages: vec![],
                 cover_url: None,
                 source_id: "test".to_string(),
             },
@@ -156,6 +206,8 @@ mod unit_tests {
                 authors: vec!["Kishimoto".to_string()],
                 description: None,
                 tags: vec!["Action".to_string()],
+                content_rating: None,
+                available_languages: vec![],
                 cover_url: None,
                 source_id: "test".to_string(),
             },
@@ -166,6 +218,8 @@ mod unit_tests {
                 authors: vec!["Oda".to_string()],
                 description: None,
                 tags: vec!["Action".to_string()],
+                content_rating: None,
+                available_languages: vec![],
                 cover_url: None,
                 source_id: "test2".to_string(),
             },
@@ -180,6 +234,968 @@ mod unit_tests {
         assert_eq!(sorted_result.len(), 3);
     }
 
+    #[test]
+    fn test_paginate_results() {
+        let results: Vec<Manga> = (1..=7)
+            .map(|i| common::test_manga(&i.to_string(), &format!("Manga {}", i)))
+            .collect();
+        let ids = |page: Vec<Manga>| page.into_iter().map(|m| m.id).collect::<Vec<_>>();
+
+        // First page, with page 0 treated as page 1
+        assert_eq!(ids(results.clone().paginate(1, 3)), vec!["1", "2", "3"]);
+        assert_eq!(ids(results.clone().paginate(0, 3)), vec!["1", "2", "3"]);
+
+        // Last, partial page
+        assert_eq!(ids(results.clone().paginate(3, 3)), vec!["7"]);
+
+        // Out of range
+        assert!(results.clone().paginate(4, 3).is_empty());
+        assert!(results.clone().paginate(usize::MAX, usize::MAX).is_empty());
+        assert!(results.paginate(1, 0).is_empty());
+    }
+
+    #[test]
+    fn test_borrowing_scores_match_consuming_methods() {
+        let manga = |id: &str, title: &str, tags: usize, described: bool| Manga {
+            tags: (0..tags).map(|i| format!("tag-{i}")).collect(),
+            description: described.then(|| "A story".to_string()),
+            ..common::test_manga(id, title)
+        };
+        let results = vec![
+            manga("1", "Naruto Gaiden", 0, false),
+            manga("2", "Boruto", 5, true),
+            manga("3", "Naruto", 3, true),
+            manga("4", "The Naruto Story", 1, false),
+        ];
+
+        let scores = results.relevance_scores("naruto");
+        let titles: Vec<&str> = scores.iter().map(|(title, _)| title.as_str()).collect();
+        assert_eq!(
+            titles,
+            ["Naruto Gaiden", "Boruto", "Naruto", "The Naruto Story"]
+        );
+
+        // Ordering by the previewed scores reproduces the consuming sort
+        let mut by_score = scores.clone();
+        by_score.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.len().cmp(&b.0.len())));
+        let sorted: Vec<String> = results
+            .clone()
+            .sort_by_query_relevance("naruto")
+            .into_iter()
+            .map(|m| m.title)
+            .collect();
+        let previewed: Vec<String> = by_score.into_iter().map(|(title, _)| title).collect();
+        assert_eq!(previewed, sorted);
+        assert_eq!(sorted[0], "Naruto");
+
+        for min_score in 0..=7 {
+            assert_eq!(
+                results.count_popular(min_score),
+                results.clone().filter_popular(min_score).len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_filter_has_cover() {
+        let with_cover = |id: &str, cover: Option<&str>| Manga {
+            cover_url: cover.map(String::from),
+            ..common::test_manga(id, id)
+        };
+        let results = vec![
+            with_cover("1", Some("https://example.com/1.jpg")),
+            with_cover("2", None),
+            with_cover("3", Some("")),
+            with_cover("4", Some("   ")),
+            with_cover("5", Some("https://example.com/5.png")),
+        ];
+
+        let ids: Vec<String> = results
+            .filter_has_cover()
+            .into_iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(ids, ["1", "5"]);
+    }
+
+    #[test]
+    fn test_group_by_source() {
+        let from = |source: &str, id: &str| Manga {
+            source_id: source.to_string(),
+            ..common::test_manga(id, id)
+        };
+        let results = vec![
+            from("mgd", "m1"),
+            from("kmg", "k1"),
+            from("mgd", "m2"),
+            from("asura", "a1"),
+            from("mgd", "m3"),
+            from("kmg", "k2"),
+        ];
+
+        let groups = results.group_by_source();
+        let keys: Vec<&str> = groups.keys().map(String::as_str).collect();
+        assert_eq!(keys, ["asura", "kmg", "mgd"]);
+
+        let ids =
+            |source: &str| -> Vec<&str> { groups[source].iter().map(|m| m.id.as_str()).collect() };
+        assert_eq!(ids("asura"), ["a1"]);
+        assert_eq!(ids("kmg"), ["k1", "k2"]);
+        assert_eq!(ids("mgd"), ["m1", "m2", "m3"]);
+
+        assert!(Vec::<Manga>::new().group_by_source().is_empty());
+    }
+
+    #[test]
+    fn test_interleave_by_source() {
+        let from = |source: &str, id: &str| Manga {
+            source_id: source.to_string(),
+            ..common::test_manga(id, id)
+        };
+        let results = vec![
+            from("mgd", "m1"),
+            from("mgd", "m2"),
+            from("mgd", "m3"),
+            from("mgd", "m4"),
+            from("kmg", "k1"),
+            from("asura", "a1"),
+            from("asura", "a2"),
+        ];
+
+        let interleaved = results.interleave_by_source();
+        let ids: Vec<&str> = interleaved.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["m1", "k1", "a1", "m2", "a2", "m3", "m4"]);
+
+        assert!(Vec::<Manga>::new().interleave_by_source().is_empty());
+    }
+
+    #[test]
+    fn test_manga_slug() {
+        let from = |source: &str, id: &str| Manga {
+            source_id: source.to_string(),
+            ..common::test_manga(id, "Oshi no Ko: The Movie!")
+        };
+
+        // Same title, different sources or IDs
+        let mangadex = from("mgd", "296cbc31");
+        let kissmanga = from("kmg", "296cbc31");
+        let other_entry = from("mgd", "a0b1c2d3");
+        assert_ne!(mangadex.slug(), kissmanga.slug());
+        assert_ne!(mangadex.slug(), other_entry.slug());
+
+        // Stable for the same manga, and independent of title changes
+        let slug = mangadex.slug();
+        assert_eq!(slug, from("mgd", "296cbc31").slug());
+        assert!(slug.starts_with("oshi-no-ko-the-movie-mgd-"));
+        let hash = slug.rsplit('-').next().unwrap();
+        assert_eq!(hash.len(), 8);
+        let renamed = Manga {
+            title: "Oshi no Ko".to_string(),
+            ..mangadex.clone()
+        };
+        assert!(renamed.slug().ends_with(&format!("-mgd-{}", hash)));
+
+        // Titles without usable characters still produce a valid slug
+        let untitled = Manga {
+            title: "???".to_string(),
+            ..mangadex
+        };
+        assert!(untitled.slug().starts_with("untitled-mgd-"));
+    }
+
+    #[test]
+    fn test_manga_and_chapter_display() {
+        let manga = Manga {
+            source_id: "mgd".to_string(),
+            ..common::test_manga("op", "One Piece")
+        };
+        assert_eq!(manga.to_string(), "One Piece [mgd]");
+        assert_eq!(format!("{}", manga), "One Piece [mgd]");
+
+        let mut chapter = common::test_chapter("ch", 1045.5);
+        chapter.title = "Special".to_string();
+        assert_eq!(chapter.to_string(), "Ch. 1045.5: Special");
+
+        let chapter = common::test_chapter("ch", 7.0);
+        assert_eq!(chapter.to_string(), "Ch. 7: Chapter 7");
+    }
+
+    #[test]
+    fn test_manga_merge_fills_missing_fields() {
+        use tosho::types::ContentRating;
+
+        let mut stored = common::test_manga("op", "One Piece");
+        stored.description = Some("Pirates".to_string());
+
+        let mut details = common::test_manga("other-id", "One Piece (Details)");
+        details.source_id = "other".to_string();
+        details.url = Some("https://example.com/op".to_string());
+        details.cover_url = Some("https://example.com/op.jpg".to_string());
+        details.content_rating = Some(ContentRating::Safe);
+        details.description = Some("A story about pirates".to_string());
+
+        stored.merge_from(&details);
+
+        assert_eq!(stored.id, "op");
+        assert_eq!(stored.source_id, "mock");
+        assert_eq!(stored.title, "One Piece");
+        assert_eq!(stored.url.as_deref(), Some("https://example.com/op"));
+        assert_eq!(
+            stored.cover_url.as_deref(),
+            Some("https://example.com/op.jpg")
+        );
+        assert_eq!(stored.content_rating, Some(ContentRating::Safe));
+        assert_eq!(stored.description.as_deref(), Some("A story about pirates"));
+
+        // Existing values win over the other record, and a shorter description is ignored
+        let mut sparse = common::test_manga("op", "Ignored");
+        sparse.cover_url = Some("https://example.com/other.jpg".to_string());
+        sparse.description = Some("Short".to_string());
+        stored.merge_from(&sparse);
+
+        assert_eq!(stored.title, "One Piece");
+        assert_eq!(
+            stored.cover_url.as_deref(),
+            Some("https://example.com/op.jpg")
+        );
+        assert_eq!(stored.description.as_deref(), Some("A story about pirates"));
+    }
+
+    #[test]
+    fn test_manga_merge_unions_lists() {
+        let mut stored = common::test_manga("op", "One Piece");
+        stored.tags = vec!["Action".to_string(), "Comedy".to_string()];
+        stored.authors = vec!["Oda".to_string()];
+
+        let mut details = common::test_manga("op", "One Piece");
+        details.tags = vec!["Adventure".to_string(), "Action".to_string()];
+        details.authors = vec!["Oda".to_string(), "Oda Eiichiro".to_string()];
+        details.available_languages = vec!["en".to_string(), "ja".to_string()];
+
+        stored.merge_from(&details);
+
+        assert_eq!(stored.tags, vec!["Action", "Comedy", "Adventure"]);
+        assert_eq!(stored.authors, vec!["Oda", "Oda Eiichiro"]);
+        assert_eq!(stored.available_languages, vec!["en", "ja"]);
+    }
+
+    #[test]
+    fn test_content_eq_detects_metadata_changes() {
+        let stored = common::test_manga("op", "One Piece");
+        let mut fetched = stored.clone();
+        assert!(stored.content_eq(&fetched));
+
+        fetched.description = Some("Pirates".to_string());
+        assert!(!stored.content_eq(&fetched));
+
+        // List order is part of the metadata
+        let mut a = common::test_manga("op", "One Piece");
+        a.tags = vec!["Action".to_string(), "Adventure".to_string()];
+        let mut b = a.clone();
+        b.tags.reverse();
+        assert!(!a.content_eq(&b));
+
+        // Page URLs differ between fetches of an unchanged chapter
+        let stored = common::test_chapter("ch", 1.0);
+        let mut fetched = stored.clone();
+        fetched.pages = vec!["https://cdn.example.com/1.jpg".to_string()];
+        assert!(stored.content_eq(&fetched));
+
+        fetched.volume = Some(1.0);
+        assert!(!stored.content_eq(&fetched));
+
+        let nan = common::test_chapter("ch", f64::NAN);
+        assert!(nan.content_eq(&nan.clone()));
+    }
+
+    #[test]
+    fn test_parse_chapter_range_open_and_decimal() {
+        use tosho::types::parse_chapter_range_against;
+
+        let available = [1.0, 2.0, 3.0, 4.5, 5.0, 5.5, 8.0, 9.0, 50.0, 51.5];
+
+        // Closed ranges keep the decimal chapters in between
+        let selected = parse_chapter_range_against("5-8", &available).unwrap();
+        assert_eq!(selected, vec![5.0, 5.5, 8.0]);
+
+        // Open ends resolve against what is available
+        let selected = parse_chapter_range_against("50-", &available).unwrap();
+        assert_eq!(selected, vec![50.0, 51.5]);
+        let selected = parse_chapter_range_against("-2", &available).unwrap();
+        assert_eq!(selected, vec![1.0, 2.0]);
+
+        // Overlapping items are merged and sorted
+        let selected = parse_chapter_range_against(" 9, 1,3-5, 4.5 ", &available).unwrap();
+        assert_eq!(selected, vec![1.0, 3.0, 4.5, 5.0, 9.0]);
+
+        // Numbers that aren't available are not invented
+        assert!(
+            parse_chapter_range_against("6-7", &available)
+                .unwrap()
+                .is_empty()
+        );
+
+        for invalid in ["-", "abc", "8-5", "1-2-3"] {
+            assert!(
+                matches!(
+                    parse_chapter_range_against(invalid, &available),
+                    Err(Error::Parse(_))
+                ),
+                "{:?} should be rejected",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_chapter_number_from_title() {
+        use tosho::types::parse_chapter_number_from_title;
+
+        assert_eq!(parse_chapter_number_from_title("Ch. 10.5"), Some(10.5));
+        assert_eq!(parse_chapter_number_from_title("ch.3"), Some(3.0));
+        assert_eq!(
+            parse_chapter_number_from_title("Chapter 10.5 - Omake"),
+            Some(10.5)
+        );
+        assert_eq!(parse_chapter_number_from_title("CHAPTER 42"), Some(42.0));
+        assert_eq!(parse_chapter_number_from_title("#10.5"), Some(10.5));
+        assert_eq!(
+            parse_chapter_number_from_title("Vol. 3 Ch. 21: Storm"),
+            Some(21.0)
+        );
+
+        // Numbers without a chapter marker are part of the title
+        assert_eq!(parse_chapter_number_from_title("Omake"), None);
+        assert_eq!(parse_chapter_number_from_title("2 Years Later"), None);
+        assert_eq!(parse_chapter_number_from_title("Chaos 7"), None);
+        assert_eq!(parse_chapter_number_from_title(""), None);
+    }
+
+    #[test]
+    fn test_normalize_title() {
+        use tosho::types::normalize_title;
+
+        // Accents and case
+        assert_eq!(normalize_title("Pokémon Adventures"), "pokemon adventures");
+        assert_eq!(normalize_title("HÉROS"), "heros");
+
+        // Parenthetical and bracketed suffixes
+        assert_eq!(normalize_title("One Piece (Official Colored)"), "one piece");
+        assert_eq!(normalize_title("Berserk [Digital] {Raw}"), "berserk");
+
+        // Trailing edition markers and separators
+        assert_eq!(normalize_title("Dragon Ball - Colored"), "dragon ball");
+        assert_eq!(
+            normalize_title("Dragon Ball: Official Colored"),
+            "dragon ball"
+        );
+
+        // Whitespace is collapsed, and marker words that are the whole title are kept
+        assert_eq!(normalize_title("  Spy   x  Family  "), "spy x family");
+        assert_eq!(normalize_title("Colored"), "colored");
+    }
+
+    #[test]
+    fn test_dedupe_by_normalized_title() {
+        let manga = |id: &str, title: &str| Manga {
+            id: id.to_string(),
+            url: None,
+            title: title.to_string(),
+            authors: vec![],
+            description: None,
+            tags: vec![],
+            content_rating: None,
+            available_languages: vec![],
+            cover_url: None,
+            source_id: "test".to_string(),
+        };
+
+        let results = vec![
+            manga("1", "One Piece"),
+            manga("2", "ONE PIECE (Official Colored)"),
+            manga("3", "Pokémon Adventures"),
+            manga("4", "Pokemon Adventures"),
+        ]
+        .dedupe_by_title();
+
+        let ids: Vec<&str> = results.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "3"]);
+    }
+
+    #[tokio::test]
+    async fn test_flatten_dedupe_modes() {
+        use common::{MockSource, test_manga};
+
+        let mut sources = Sources::new();
+        sources.add(MockSource::named("first").with_manga(vec![
+            test_manga("a1", "One Piece"),
+            test_manga("a2", "Naruto"),
+            test_manga("a2", "Naruto"),
+        ]));
+        sources.add(MockSource::named("second").with_manga(vec![
+            test_manga("b1", "ONE PIECE"),
+            test_manga("b2", "Narutoo"),
+        ]));
+
+        let ids = |results: Vec<Manga>| -> Vec<String> {
+            results
+                .into_iter()
+                .map(|m| format!("{}:{}", m.source_id, m.id))
+                .collect()
+        };
+
+        // Off by default
+        let all = sources.search("x").flatten().await.unwrap();
+        assert_eq!(all.len(), 5);
+
+        let by_title = sources
+            .search("x")
+            .dedupe(DedupeMode::ByTitle)
+            .flatten()
+            .await
+            .unwrap();
+        assert_eq!(ids(by_title), ["first:a1", "first:a2", "second:b2"]);
+
+        let by_source_id = sources
+            .search("x")
+            .dedupe(DedupeMode::BySourceId)
+            .flatten()
+            .await
+            .unwrap();
+        assert_eq!(
+            ids(by_source_id),
+            ["first:a1", "first:a2", "second:b1", "second:b2"]
+        );
+
+        let fuzzy = sources
+            .search("x")
+            .dedupe(DedupeMode::Fuzzy(0.8))
+            .flatten()
+            .await
+            .unwrap();
+        assert_eq!(ids(fuzzy), ["first:a1", "first:a2"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_passes_large_tag_list_to_every_source() {
+        use common::{MockSource, test_manga};
+
+        let mut sources = Sources::new();
+        for id in ["first", "second", "third"] {
+            // Echoes the tags it received back as a single result
+            sources.add(MockSource::named(id).with_search(|params| {
+                let mut manga = test_manga(&params.include_tags.len().to_string(), &params.query);
+                manga.tags = params.include_tags.clone();
+                vec![manga]
+            }));
+        }
+
+        let tags: Vec<String> = (0..500).map(|i| format!("tag-{i}")).collect();
+        let grouped = sources
+            .search("query")
+            .include_tags(tags.clone())
+            .group()
+            .await;
+
+        assert_eq!(grouped.len(), 3);
+        for (source_id, result) in grouped {
+            let manga = result.unwrap();
+            assert_eq!(manga.len(), 1, "{source_id}");
+            assert_eq!(manga[0].title, "query");
+            assert_eq!(manga[0].tags, tags, "{source_id}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flatten_paged_uses_global_window() {
+        use common::{MockSource, test_manga};
+
+        // Honors offset and limit like a real paginated API
+        let paged = |id: &'static str, prefix: &'static str| {
+            MockSource::named(id).with_search(move |params| {
+                (1..=4)
+                    .map(|n| test_manga(&format!("{}{}", prefix, n), &format!("{} {}", prefix, n)))
+                    .skip(params.offset.unwrap_or(0))
+                    .take(params.limit.unwrap_or(usize::MAX))
+                    .collect()
+            })
+        };
+        let mut sources = Sources::new();
+        sources.add(paged("first", "a"));
+        sources.add(paged("second", "b"));
+
+        // Pages alternate sources: a1, b1, a2, b2, ...; page 2 of 3 is b2, a3, b3
+        let page = sources.search("x").flatten_paged(2, 3).await.unwrap();
+        let ids: Vec<&str> = page.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["b2", "a3", "b3"]);
+
+        // The builder's own offset is ignored
+        let page = sources
+            .search("x")
+            .offset(3)
+            .flatten_paged(1, 2)
+            .await
+            .unwrap();
+        let ids: Vec<&str> = page.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["a1", "b1"]);
+
+        // The total limit caps the interleaved list to a1, b1, a2, b2, a3
+        let page = sources
+            .search("x")
+            .total_limit(5)
+            .flatten_paged(2, 4)
+            .await
+            .unwrap();
+        let ids: Vec<&str> = page.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["a3"]);
+
+        // Per-source offsets, for comparison, skip into both sources
+        let per_source = sources
+            .search("x")
+            .offset(3)
+            .limit(3)
+            .flatten()
+            .await
+            .unwrap();
+        let ids: Vec<&str> = per_source.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["a4", "b4"]);
+    }
+
+    #[tokio::test]
+    async fn test_execute_captures_query_context() {
+        use common::{MockSource, test_manga};
+
+        let mut source = MockSource::with_pages(vec![]);
+        source.manga = vec![
+            test_manga("1", "Berserk of Gluttony"),
+            test_manga("2", "Berserk"),
+            test_manga("3", "BERSERK"),
+        ];
+        let mut sources = Sources::new();
+        sources.add(source);
+        sources
+            .add(MockSource::named("failing").failing(|| Error::source("failing", "unavailable")));
+
+        let result = sources.search("berserk").execute().await;
+        assert_eq!(result.query, "berserk");
+        assert_eq!(result.sources_queried, ["mock", "failing"]);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].0, "failing");
+        assert_eq!(result.manga.len(), 3);
+
+        let result = result.dedupe(DedupeMode::ByTitle).sorted();
+        let ids: Vec<&str> = result.manga.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["2", "1"]);
+    }
+
+    #[test]
+    fn test_search_result_vec_conversions() {
+        let manga = vec![
+            common::test_manga("1", "Berserk"),
+            common::test_manga("2", "Vagabond"),
+        ];
+
+        let result = SearchResult::from(manga.clone());
+        assert!(result.query.is_empty());
+        assert!(result.sources_queried.is_empty());
+        assert!(result.errors.is_empty());
+
+        let round_trip: Vec<Manga> = result.into();
+        let ids = |manga: &[Manga]| manga.iter().map(|m| m.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&round_trip), ids(&manga));
+
+        // Unwrapping a rich result keeps only the manga
+        let rich = SearchResult {
+            query: "berserk".to_string(),
+            sources_queried: vec!["mock".to_string()],
+            errors: vec![("failing".to_string(), "unavailable".to_string())],
+            ..manga.clone().into()
+        };
+        let plain: Vec<Manga> = rich.into();
+        assert_eq!(ids(&plain), ids(&manga));
+    }
+
+    #[tokio::test]
+    async fn test_for_each_streams_results() {
+        use common::{MockSource, test_manga};
+
+        let mut source = MockSource::with_pages(vec![]);
+        source.manga = (1..=4)
+            .map(|i| test_manga(&i.to_string(), &format!("Manga {}", i)))
+            .collect();
+        let mut sources = Sources::new();
+        sources.add(source);
+        sources
+            .add(MockSource::named("failing").failing(|| Error::source("failing", "unavailable")));
+
+        let mut streamed = Vec::new();
+        let mut errors = Vec::new();
+        sources
+            .search("manga")
+            .for_each_with_errors(
+                |manga| streamed.push(manga),
+                |source_id, error| errors.push((source_id.to_string(), error.to_string())),
+            )
+            .await;
+
+        let flattened = sources.search("manga").flatten().await.unwrap();
+        let ids = |manga: &[Manga]| manga.iter().map(|m| m.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&streamed), ids(&flattened));
+        assert!(streamed.iter().all(|m| m.source_id == "mock"));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, "failing");
+
+        // total_limit stops delivery early
+        let mut limited = Vec::new();
+        sources
+            .search("manga")
+            .total_limit(2)
+            .for_each(|manga| limited.push(manga.id))
+            .await;
+        assert_eq!(limited, ["1", "2"]);
+    }
+
+    #[tokio::test]
+    async fn test_dropping_search_stream_cancels_pending_sources() {
+        use common::{MockSource, test_manga};
+        use futures::StreamExt;
+        use std::sync::atomic::Ordering;
+        use std::time::Duration;
+
+        // Both hanging sources share one set of counters
+        let slow_a = MockSource::named("slow-a").with_delay(Duration::MAX);
+        let mut slow_b = MockSource::named("slow-b").with_delay(Duration::MAX);
+        slow_b.calls = slow_a.calls.clone();
+        slow_b.in_flight = slow_a.in_flight.clone();
+        let started = slow_a.calls.clone();
+        let pending = slow_a.in_flight.clone();
+
+        // The hanging sources come first so they are polled before the fast one answers
+        let mut sources = Sources::new();
+        sources.add(slow_a);
+        sources.add(slow_b);
+        let mut source = MockSource::with_pages(vec![]);
+        source.manga = vec![test_manga("1", "Manga 1")];
+        sources.add(source);
+
+        let mut results = sources.search("manga").stream();
+        let (source_id, first) = results.next().await.unwrap();
+        assert_eq!(source_id, "mock");
+        assert_eq!(first.unwrap().len(), 1);
+        assert_eq!(started.load(Ordering::SeqCst), 2);
+        assert_eq!(pending.load(Ordering::SeqCst), 2);
+
+        drop(results);
+        assert_eq!(pending.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_deadline_returns_partial_results() {
+        use common::{MockSource, test_manga};
+        use std::time::{Duration, Instant};
+
+        let mut sources = Sources::new();
+        sources.add(
+            MockSource::named("slow")
+                .with_manga(vec![test_manga("slow", "slow")])
+                .with_delay(Duration::from_secs(5)),
+        );
+        sources.add(
+            MockSource::named("fast")
+                .with_manga(vec![test_manga("fast", "fast")])
+                .with_delay(Duration::from_millis(10)),
+        );
+
+        let started = Instant::now();
+        let results = sources
+            .search("manga")
+            .deadline(Duration::from_millis(300))
+            .flatten()
+            .await
+            .unwrap();
+        assert!(started.elapsed() < Duration::from_secs(2));
+        let ids: Vec<&str> = results.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["fast"]);
+
+        let grouped = sources
+            .search("manga")
+            .deadline(Duration::from_millis(300))
+            .group()
+            .await;
+        assert_eq!(grouped.len(), 1);
+        assert_eq!(grouped[0].0, "fast");
+
+        // Nothing answering in time is an empty result, not an error
+        let results = sources
+            .search("manga")
+            .deadline(Duration::from_millis(1))
+            .flatten()
+            .await
+            .unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_total_limit_caps_merged_results() {
+        use common::{MockSource, test_manga};
+
+        let fifteen = |id: &'static str| {
+            MockSource::named(id).with_manga(
+                (0..15)
+                    .map(|n| test_manga(&n.to_string(), &format!("{} {}", id, n)))
+                    .collect(),
+            )
+        };
+        let mut sources = Sources::new();
+        sources.add(fifteen("first"));
+        sources.add(fifteen("second"));
+
+        // The per-source limit applies to each source
+        let per_source = sources.search("x").limit(10).flatten().await.unwrap();
+        assert_eq!(per_source.len(), 20);
+
+        let capped = sources.search("x").total_limit(10).flatten().await.unwrap();
+        assert_eq!(capped.len(), 10);
+
+        let executed = sources.search("x").total_limit(10).execute().await;
+        assert_eq!(executed.manga.len(), 10);
+
+        // A cap above the available results changes nothing
+        let all = sources
+            .search("x")
+            .total_limit(100)
+            .flatten()
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 30);
+    }
+
+    #[tokio::test]
+    async fn test_from_sources_fallback() {
+        use common::{MockSource, test_manga};
+        use std::sync::atomic::Ordering;
+
+        let broken = MockSource::named("broken").failing(|| Error::source("broken", "unavailable"));
+        let calls = broken.calls.clone();
+        let mut working = MockSource::with_pages(vec![]);
+        working.manga = vec![test_manga("1", "Dragon Ball")];
+        let mut sources = Sources::new();
+        sources.add(broken);
+        sources.add(working);
+
+        let results = sources
+            .search("dragon ball")
+            .from_sources_fallback(&["broken", "mock"])
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].source_id, "mock");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Later sources aren't queried once one succeeds
+        sources
+            .search("dragon ball")
+            .from_sources_fallback(&["mock", "broken"])
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Only fails when every source does
+        let all_failed = sources
+            .search("dragon ball")
+            .from_sources_fallback(&["broken", "missing"])
+            .await;
+        assert!(matches!(all_failed, Err(Error::Other(_))));
+    }
+
+    #[tokio::test]
+    async fn test_from_sources_fallback_applies_builder_settings() {
+        use common::{MockSource, test_manga};
+        use std::sync::atomic::Ordering;
+        use std::time::{Duration, Instant};
+
+        let slow = MockSource::named("slow")
+            .with_manga(vec![test_manga("slow", "Slow")])
+            .with_delay(Duration::from_secs(5));
+        let sorted = MockSource::named("sorted")
+            .with_manga(vec![
+                test_manga("1", "Dragon Ball"),
+                test_manga("2", "DRAGON BALL"),
+                test_manga("3", "Dragon Ball Z"),
+            ])
+            .with_capabilities(&[Capability::Sorting]);
+        let plain = MockSource::named("plain").with_manga(vec![test_manga("p", "Plain")]);
+        let plain_calls = plain.calls.clone();
+        let mut sources = Sources::new();
+        sources.add(slow);
+        sources.add(sorted);
+        sources.add(plain);
+
+        // Sources lacking a required capability are skipped, not queried
+        let results = sources
+            .search("dragon ball")
+            .require_capability(Capability::Sorting)
+            .from_sources_fallback(&["plain", "sorted"])
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].source_id, "sorted");
+        assert_eq!(plain_calls.load(Ordering::SeqCst), 0);
+
+        // The winning result is deduplicated, then capped
+        let results = sources
+            .search("dragon ball")
+            .dedupe(DedupeMode::ByTitle)
+            .total_limit(2)
+            .from_sources_fallback(&["sorted"])
+            .await
+            .unwrap();
+        let ids: Vec<&str> = results.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["1", "3"]);
+
+        // The deadline covers the whole chain; a source still pending is left out
+        let started = Instant::now();
+        let results = sources
+            .search("dragon ball")
+            .deadline(Duration::from_millis(100))
+            .from_sources_fallback(&["slow", "plain"])
+            .await
+            .unwrap();
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert!(results.is_empty());
+        assert_eq!(plain_calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_pages_detailed_default() {
+        let pages = vec![
+            "http://localhost/1.jpg".to_string(),
+            "http://localhost/2.jpg".to_string(),
+        ];
+        let source = common::MockSource::with_pages(pages.clone());
+
+        let detailed = source.get_pages_detailed("ch1").await.unwrap();
+        let urls: Vec<String> = detailed.iter().map(|p| p.url.clone()).collect();
+        assert_eq!(urls, pages);
+        assert!(
+            detailed
+                .iter()
+                .all(|p| p.width.is_none() && p.height.is_none() && p.referer.is_none())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_page_count_default() {
+        let pages: Vec<String> = (1..=40)
+            .map(|n| format!("http://localhost/{}.jpg", n))
+            .collect();
+        let source = common::MockSource::with_pages(pages);
+
+        let count = source.get_page_count("ch1").await.unwrap();
+        assert_eq!(count, source.get_pages("ch1").await.unwrap().len());
+        assert_eq!(count, 40);
+    }
+
+    #[tokio::test]
+    async fn test_get_or_err() {
+        let mut sources = Sources::new();
+        sources.add(common::MockSource::with_pages(vec![]));
+
+        assert_eq!(sources.get_or_err("mock").unwrap().id(), "mock");
+
+        let error = sources.get_or_err("nonexistent-source").err().unwrap();
+        assert!(matches!(error, Error::NotFound(_)));
+        assert!(error.to_string().contains("nonexistent-source"));
+
+        // from_source reports the same error
+        let error = sources
+            .search("x")
+            .from_source("nonexistent-source")
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("nonexistent-source"));
+    }
+
+    #[tokio::test]
+    async fn test_require_capability_skips_incapable_sources() {
+        use common::{MockSource, test_manga};
+        use std::sync::atomic::Ordering;
+
+        let tagged = MockSource::named("tagged")
+            .with_manga(vec![test_manga("tagged", "tagged")])
+            .with_capabilities(&[Capability::TagFiltering, Capability::Sorting]);
+        let plain = MockSource::named("plain").with_manga(vec![test_manga("plain", "plain")]);
+        let tagged_calls = tagged.calls.clone();
+        let plain_calls = plain.calls.clone();
+        let mut sources = Sources::new();
+        sources.add(tagged);
+        sources.add(plain);
+
+        let results = sources
+            .search("x")
+            .include_tags(vec!["Romance".to_string()])
+            .require_capability(Capability::TagFiltering)
+            .flatten()
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].source_id, "tagged");
+        assert_eq!(tagged_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(plain_calls.load(Ordering::SeqCst), 0);
+
+        // Every required capability must be supported
+        let grouped = sources
+            .search("x")
+            .require_capability(Capability::TagFiltering)
+            .require_capability(Capability::Pagination)
+            .group()
+            .await;
+        assert!(grouped.is_empty());
+
+        // Without requirements every source is queried
+        assert_eq!(sources.search("x").group().await.len(), 2);
+    }
+
+    #[test]
+    fn test_search_builder_explain() {
+        let mut sources = Sources::new();
+        sources.add(tosho::sources::MangaDexSource::new());
+        sources.add(common::MockSource::with_pages(vec![]));
+
+        let explained = sources.search("tom & jerry").explain();
+        assert_eq!(explained.len(), 2);
+
+        let (source_id, requests) = &explained[0];
+        assert_eq!(source_id, "mgd");
+        assert!(requests[0].1.contains("title=tom%20%26%20jerry"));
+
+        // Sources without an explain() override report nothing
+        assert_eq!(explained[1], ("mock".to_string(), vec![]));
+
+        let explained = sources
+            .search("tom & jerry")
+            .require_capability(Capability::Sorting)
+            .explain();
+        assert_eq!(explained.len(), 1);
+    }
+
+    #[test]
+    fn test_build_query_encoding() {
+        use tosho::net::build_query;
+
+        let query = build_query(&[
+            ("title", "tom & jerry".to_string()),
+            ("tags[]", "slice of life".to_string()),
+            ("q", "🍥=naruto".to_string()),
+        ]);
+
+        assert_eq!(
+            query,
+            "title=tom%20%26%20jerry&tags%5B%5D=slice%20of%20life&q=%F0%9F%8D%A5%3Dnaruto"
+        );
+        assert_eq!(build_query(&[]), "");
+    }
+
     #[test]
     fn test_error_handling() {
         // Test that our error type can be created and displayed
@@ -190,6 +1206,77 @@ mod unit_tests {
         let error = Error::not_found("Test not found error");
         let error_string = format!("{}", error);
         assert!(error_string.contains("Test not found error"));
+
+        let error = Error::conversion("Test conversion error");
+        assert!(matches!(error, Error::Conversion(_)));
+        assert_eq!(
+            format!("{}", error),
+            "Conversion error: Test conversion error"
+        );
+    }
+
+    #[test]
+    fn test_search_builder_params_mut() {
+        let sources = Sources::new();
+        let mut builder = sources.search("one piece").limit(10);
+
+        assert_eq!(builder.params().query, "one piece");
+        assert_eq!(builder.params().limit, Some(10));
+
+        builder.params_mut().limit = Some(25);
+        builder.params_mut().exclude_tags.push("Ecchi".to_string());
+
+        let params = builder.build();
+        assert_eq!(params.limit, Some(25));
+        assert_eq!(params.exclude_tags, vec!["Ecchi".to_string()]);
+    }
+
+    #[test]
+    fn test_search_builder_clone_forks_base_query() {
+        let sources = Sources::new();
+        let base = sources
+            .search("one piece")
+            .limit(20)
+            .include_tags(vec!["Action".to_string()]);
+
+        let by_title = base.clone().sort_by(SortOrder::Title).build();
+        let by_update = base.sort_by(SortOrder::UpdatedAt).build();
+
+        assert!(matches!(by_title.sort_by, Some(SortOrder::Title)));
+        assert!(matches!(by_update.sort_by, Some(SortOrder::UpdatedAt)));
+
+        // Everything but the sort order is shared
+        let without_sort = |params: SearchParams| SearchParams {
+            sort_by: None,
+            ..params
+        };
+        assert_eq!(
+            serde_json::to_value(without_sort(by_title)).unwrap(),
+            serde_json::to_value(without_sort(by_update)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_search_with_prebuilt_params() {
+        let preset = SearchParamsBuilder::default()
+            .query("one piece".to_string())
+            .limit(Some(20))
+            .offset(Some(40))
+            .include_tags(vec!["Action".to_string()])
+            .exclude_tags(vec!["Horror".to_string()])
+            .sort_by(Some(SortOrder::Title))
+            .build()
+            .unwrap();
+
+        let sources = Sources::new();
+        let params = sources.search_with(preset.clone()).build();
+
+        assert_eq!(params.query, preset.query);
+        assert_eq!(params.limit, preset.limit);
+        assert_eq!(params.offset, preset.offset);
+        assert_eq!(params.include_tags, preset.include_tags);
+        assert_eq!(params.exclude_tags, preset.exclude_tags);
+        assert!(matches!(params.sort_by, Some(SortOrder::Title)));
     }
 
     #[test]
@@ -219,6 +1306,45 @@ mod unit_tests {
         assert!(params.limit.is_none());
     }
 
+    #[test]
+    fn test_search_params_merge_defaults() {
+        let defaults = SearchParams {
+            query: "default query".to_string(),
+            limit: Some(50),
+            offset: Some(10),
+            include_tags: vec!["Action".to_string()],
+            exclude_tags: vec!["Ecchi".to_string()],
+            sort_by: Some(SortOrder::UpdatedAt),
+        };
+
+        // Unset fields are filled from the defaults
+        let merged = SearchParams::default().merge_defaults(&defaults);
+        assert_eq!(merged.query, "default query");
+        assert_eq!(merged.limit, Some(50));
+        assert_eq!(merged.offset, Some(10));
+        assert_eq!(merged.include_tags, vec!["Action".to_string()]);
+        assert_eq!(merged.exclude_tags, vec!["Ecchi".to_string()]);
+        assert!(matches!(merged.sort_by, Some(SortOrder::UpdatedAt)));
+
+        // Explicitly set fields are kept, and tag lists replace rather than append
+        let params = SearchParams {
+            query: "one piece".to_string(),
+            limit: Some(5),
+            offset: Some(0),
+            include_tags: vec!["Adventure".to_string()],
+            exclude_tags: vec!["Horror".to_string(), "Gore".to_string()],
+            sort_by: Some(SortOrder::Title),
+        };
+        let merged = params.merge_defaults(&defaults);
+        assert_eq!(merged.query, "one piece");
+        assert_eq!(merged.limit, Some(5));
+        assert_eq!(merged.offset, Some(0));
+        assert_eq!(merged.include_tags, vec!["Adventure".to_string()]);
+        assert_eq!(merged.exclude_tags.len(), 2);
+        assert!(!merged.exclude_tags.contains(&"Ecchi".to_string()));
+        assert!(matches!(merged.sort_by, Some(SortOrder::Title)));
+    }
+
     #[test]
     fn test_chapter_decimal_numbers() {
         let chapter = Chapter {
@@ -227,6 +1353,7 @@ mod unit_tests {
             volume: None,
             number: 5.5,
             pages: vec![],
+            page_count: None,
             manga_id: "test".to_string(),
             source_id: "test".to_string(),
         };
@@ -236,32 +1363,854 @@ mod unit_tests {
     }
 
     #[test]
-    fn test_empty_collections() {
-        let manga = Manga {
-            id: "test".to_string(),
-            url: None,
-            title: "Test".to_string(),
-            authors: vec![],
-            description: None,
-            tags: vec![],
-            cover_url: None,
+    fn test_chapter_cmp_by_number() {
+        let chapter = |number: f64| Chapter {
+            id: format!("ch-{}", number),
+            title: format!("Chapter {}", number),
+            volume: None,
+            number,
+            pages: vec![],
+            page_count: None,
+            manga_id: "test".to_string(),
             source_id: "test".to_string(),
         };
 
+        let mut chapters = [
+            chapter(3.0),
+            chapter(f64::NAN),
+            chapter(5.5),
+            chapter(1.0),
+            chapter(6.0),
+            chapter(5.0),
+        ];
+        chapters.sort_by(Chapter::cmp_by_number);
+
+        let numbers: Vec<f64> = chapters.iter().map(|c| c.number).collect();
+        assert_eq!(&numbers[..5], &[1.0, 3.0, 5.0, 5.5, 6.0]);
+        assert!(numbers[5].is_nan());
+
+        // Same-numbered chapters are ordered by volume, unknown volumes last,
+        // and otherwise keep their original order
+        let in_volume = |id: &str, number: f64, volume: Option<f64>| Chapter {
+            id: id.to_string(),
+            volume,
+            ..chapter(number)
+        };
+        let mut chapters = [
+            in_volume("none-a", 2.0, None),
+            in_volume("vol-3", 2.0, Some(3.0)),
+            in_volume("first", 1.0, Some(9.0)),
+            in_volume("none-b", 2.0, None),
+            in_volume("vol-1", 2.0, Some(1.0)),
+        ];
+        chapters.sort_by(Chapter::cmp_by_number);
+
+        let ids: Vec<&str> = chapters.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, ["first", "vol-1", "vol-3", "none-a", "none-b"]);
+    }
+
+    #[test]
+    fn test_empty_collections() {
+        use tosho::types::{ChapterBuilder, MangaBuilder};
+
+        let manga = MangaBuilder::default()
+            .id("test")
+            .title("Test")
+            .source_id("test")
+            .build()
+            .unwrap();
+
         assert!(manga.authors.is_empty());
         assert!(manga.tags.is_empty());
         assert!(manga.description.is_none());
 
-        let chapter = Chapter {
-            id: "test".to_string(),
-            title: "Test".to_string(),
-            volume: None,
-            number: 1.0,
-            pages: vec![],
-            manga_id: "test".to_string(),
-            source_id: "test".to_string(),
-        };
+        let chapter = ChapterBuilder::default()
+            .id("test")
+            .title("Test")
+            .number(1.0)
+            .manga_id("test")
+            .source_id("test")
+            .build()
+            .unwrap();
 
         assert!(chapter.pages.is_empty());
+        assert!(chapter.volume.is_none());
+
+        // Required fields are still enforced
+        assert!(MangaBuilder::default().id("test").build().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_json_retries_on_parse_error() {
+        use tosho::net::HttpClient;
+
+        // Truncated JSON on the first request, valid JSON afterwards
+        let server = spawn_test_server(|index, _path| {
+            if index == 0 {
+                TestResponse::ok(r#"{"title": "One Pi"#)
+            } else {
+                TestResponse::ok(r#"{"title": "One Piece"}"#)
+            }
+        });
+
+        let client = HttpClient::new("test")
+            .with_rate_limit(0)
+            .with_max_retries(2);
+        let value: serde_json::Value = client
+            .get_json_with_retry_on_parse(&server.url("/manga"))
+            .await
+            .unwrap();
+
+        assert_eq!(value["title"], "One Piece");
+        assert_eq!(server.request_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_json_retry_on_parse_gives_up() {
+        use tosho::net::HttpClient;
+
+        let server = spawn_test_server(|_, _| TestResponse::ok("not json"));
+
+        let client = HttpClient::new("test")
+            .with_rate_limit(0)
+            .with_max_retries(1);
+        let result: tosho::Result<serde_json::Value> = client
+            .get_json_with_retry_on_parse(&server.url("/manga"))
+            .await;
+
+        assert!(matches!(result, Err(Error::Json(_))));
+        // One initial attempt plus one retry
+        assert_eq!(server.request_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_http_error_status_is_structured() {
+        use tosho::net::HttpClient;
+
+        let server = spawn_test_server(|_, path| TestResponse {
+            status: if path.ends_with("missing") { 404 } else { 503 },
+            headers: vec![],
+            body: vec![],
+        });
+        let client = HttpClient::new("test").with_rate_limit(0);
+
+        match client.get(&server.url("/manga/missing")).await {
+            Err(Error::Source { src, status, .. }) => {
+                assert_eq!(src, "test");
+                assert_eq!(status, Some(404));
+            }
+            other => panic!("Expected 404 source error, got {:?}", other),
+        }
+
+        let error = client.get(&server.url("/manga/busy")).await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Source {
+                status: Some(503),
+                ..
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Source error [test] (HTTP 503): Service Unavailable"
+        );
+
+        // Errors without a status keep the original format
+        assert_eq!(
+            Error::source("test", "boom").to_string(),
+            "Source error [test]: boom"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_head_returns_headers_without_body() {
+        use tosho::net::HttpClient;
+
+        let server = spawn_test_server(|_, path| {
+            if path.ends_with("missing.jpg") {
+                TestResponse {
+                    status: 404,
+                    headers: vec![],
+                    body: vec![],
+                }
+            } else {
+                TestResponse {
+                    status: 200,
+                    headers: vec![("Content-Type".to_string(), "image/jpeg".to_string())],
+                    body: vec![0xAB; 2048],
+                }
+            }
+        });
+        let client = HttpClient::new("test")
+            .with_rate_limit(0)
+            .with_max_retries(0);
+
+        let headers = client.head(&server.url("/cover.jpg")).await.unwrap();
+        let content_length: usize = headers["content-length"].to_str().unwrap().parse().unwrap();
+        let body = client.get(&server.url("/cover.jpg")).await.unwrap();
+        assert_eq!(content_length, body.len());
+        assert_eq!(headers["content-type"], "image/jpeg");
+
+        let error = client.head(&server.url("/missing.jpg")).await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Source {
+                status: Some(404),
+                ..
+            }
+        ));
+        assert_eq!(client.stats().errors, 1);
+    }
+
+    #[tokio::test]
+    async fn test_connect_timeout_fails_fast() {
+        use std::time::{Duration, Instant};
+        use tosho::net::HttpClient;
+
+        // Non-routable address: connecting never completes
+        let client = HttpClient::new("test")
+            .with_rate_limit(0)
+            .with_max_retries(0)
+            .with_connect_timeout(Duration::from_millis(1))
+            .with_read_timeout(Duration::from_secs(60));
+
+        let started = Instant::now();
+        let error = client.get("http://10.255.255.1:81/").await.unwrap_err();
+        assert!(started.elapsed() < Duration::from_secs(5));
+        // Usually a connect timeout, though sandboxed networks may reset instead
+        assert!(
+            matches!(error, Error::Network(_)),
+            "Expected network error, got {:?}",
+            error
+        );
+    }
+
+    #[tokio::test]
+    async fn test_response_size_limit() {
+        use tosho::net::HttpClient;
+
+        let server = spawn_test_server(|_, path| {
+            let size = if path == "/large" { 64 * 1024 } else { 1024 };
+            TestResponse::ok(vec![b'x'; size])
+        });
+        let client = HttpClient::new("test")
+            .with_rate_limit(0)
+            .with_max_retries(0)
+            .with_max_response_size(1024);
+
+        let error = client.get(&server.url("/large")).await.unwrap_err();
+        assert!(matches!(&error, Error::Parse(msg) if msg.contains("too large")));
+
+        // A body exactly at the limit is accepted
+        let body = client.get(&server.url("/exact")).await.unwrap();
+        assert_eq!(body.len(), 1024);
+        assert_eq!(server.request_count(), 2);
+    }
+
+    #[test]
+    fn test_source_error_keeps_cause() {
+        use std::error::Error as _;
+
+        let io_error = std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "truncated body");
+        let error = Error::source_with("test", "Failed to read chapter", io_error);
+
+        assert_eq!(
+            error.to_string(),
+            "Source error [test]: Failed to read chapter"
+        );
+        let cause = error.source().expect("cause should be exposed");
+        assert_eq!(cause.to_string(), "truncated body");
+        assert!(cause.downcast_ref::<std::io::Error>().is_some());
+
+        assert!(Error::source("test", "boom").source().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_text_legacy_encodings() {
+        use tosho::net::HttpClient;
+
+        // "ワンピース" encoded as Shift-JIS
+        let shift_jis = vec![0x83, 0x8F, 0x83, 0x93, 0x83, 0x73, 0x81, 0x5B, 0x83, 0x58];
+        let server = spawn_test_server(move |_, _| TestResponse::ok(shift_jis.clone()));
+        let client = HttpClient::new("test").with_rate_limit(0);
+
+        assert!(matches!(
+            client.get_text(&server.url("/strict")).await,
+            Err(Error::Parse(_))
+        ));
+
+        let decoded = client
+            .get_text_encoded(&server.url("/sjis"), "shift_jis")
+            .await
+            .unwrap();
+        assert_eq!(decoded, "ワンピース");
+
+        let lossy = client.get_text_lossy(&server.url("/lossy")).await.unwrap();
+        assert!(lossy.contains('\u{FFFD}'));
+
+        assert!(matches!(
+            client
+                .get_text_encoded(&server.url("/unknown"), "not-a-charset")
+                .await,
+            Err(Error::Parse(_))
+        ));
+        // Unknown charsets are rejected before any request is made
+        assert_eq!(server.request_count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_reset() {
+        use std::time::{Duration, Instant};
+        use tosho::net::RateLimiter;
+
+        let limiter = RateLimiter::new(5_000);
+        limiter.wait("a").await;
+        limiter.wait("b").await;
+
+        // Without a reset, the next request would wait five seconds
+        let start = Instant::now();
+        limiter.reset();
+        limiter.wait("a").await;
+        limiter.wait("b").await;
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        let start = Instant::now();
+        limiter.reset_source("a");
+        limiter.wait("a").await;
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_per_source_delay() {
+        use std::time::{Duration, Instant};
+        use tosho::net::RateLimiter;
+
+        let limiter = RateLimiter::new(10);
+        limiter.set_delay_for("slow", Duration::from_millis(400));
+
+        limiter.wait("fast").await;
+        limiter.wait("slow").await;
+
+        let start = Instant::now();
+        limiter.wait("fast").await;
+        let fast = start.elapsed();
+
+        let start = Instant::now();
+        limiter.wait("slow").await;
+        let slow = start.elapsed();
+
+        assert!(fast < Duration::from_millis(200), "fast waited {:?}", fast);
+        assert!(slow >= Duration::from_millis(300), "slow waited {:?}", slow);
+    }
+
+    #[tokio::test]
+    async fn test_request_middleware_adds_dynamic_header() {
+        use reqwest::RequestBuilder;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tosho::net::{HttpClient, RequestMiddleware};
+
+        // Hands out a new token for every request, like a refreshing auth session
+        struct RotatingToken(AtomicUsize);
+
+        #[async_trait::async_trait]
+        impl RequestMiddleware for RotatingToken {
+            async fn apply(&self, request: RequestBuilder) -> RequestBuilder {
+                let token = self.0.fetch_add(1, Ordering::SeqCst);
+                request.header("X-Auth-Token", format!("token-{}", token))
+            }
+        }
+
+        let server = spawn_test_server(|_, _| TestResponse::ok("ok"));
+        let client = HttpClient::new("test")
+            .with_rate_limit(0)
+            .with_middleware(Arc::new(RotatingToken(AtomicUsize::new(0))));
+
+        client.get(&server.url("/first")).await.unwrap();
+        client.get_text(&server.url("/second")).await.unwrap();
+
+        assert_eq!(
+            server.request_header(0, "x-auth-token").as_deref(),
+            Some("token-0")
+        );
+        assert_eq!(
+            server.request_header(1, "x-auth-token").as_deref(),
+            Some("token-1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_user_agent_rotation() {
+        use tosho::net::HttpClient;
+
+        let server = spawn_test_server(|_, _| TestResponse::ok("ok"));
+        let client = HttpClient::new("test")
+            .with_rate_limit(0)
+            .with_header("User-Agent", "Fixed/1.0")
+            .with_user_agents(vec!["Agent/A".to_string(), "Agent/B".to_string()]);
+
+        for _ in 0..3 {
+            client.get(&server.url("/page")).await.unwrap();
+        }
+
+        assert_eq!(
+            server.request_header(0, "user-agent").as_deref(),
+            Some("Agent/A")
+        );
+        assert_eq!(
+            server.request_header(1, "user-agent").as_deref(),
+            Some("Agent/B")
+        );
+        assert_eq!(
+            server.request_header(2, "user-agent").as_deref(),
+            Some("Agent/A")
+        );
+
+        // Without a pool the configured header is sent unchanged
+        let fixed = HttpClient::new("test")
+            .with_rate_limit(0)
+            .with_header("User-Agent", "Fixed/1.0");
+        fixed.get(&server.url("/page")).await.unwrap();
+        assert_eq!(
+            server.request_header(3, "user-agent").as_deref(),
+            Some("Fixed/1.0")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_http_client_stats() {
+        use tosho::net::{HttpClient, HttpStats};
+
+        let server = spawn_test_server(|index, _| match index {
+            0 | 1 => TestResponse::ok("{}"),
+            2 => TestResponse {
+                status: 404,
+                headers: vec![],
+                body: vec![],
+            },
+            _ => TestResponse {
+                status: 429,
+                headers: vec![],
+                body: vec![],
+            },
+        });
+
+        let client = HttpClient::new("test")
+            .with_rate_limit(0)
+            .with_max_retries(0);
+        assert_eq!(client.stats(), HttpStats::default());
+
+        client.get(&server.url("/a")).await.unwrap();
+        client.get(&server.url("/b")).await.unwrap();
+        assert!(client.get(&server.url("/missing")).await.is_err());
+        assert!(matches!(
+            client.get(&server.url("/limited")).await,
+            Err(Error::RateLimit { .. })
+        ));
+
+        // Clones report into the same counters
+        let stats = client.clone().stats();
+        assert_eq!(
+            stats,
+            HttpStats {
+                requests: 4,
+                retries: 0,
+                rate_limited: 1,
+                errors: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_sources_stats() {
+        let sources = Sources::with_all_enabled();
+        let stats = sources.stats();
+
+        assert_eq!(stats.len(), sources.len());
+        assert!(stats.values().all(|s| s.requests == 0));
+
+        // Sources without an HTTP client are left out
+        let mut sources = Sources::new();
+        sources.add(common::MockSource::with_pages(vec![]));
+        assert!(sources.stats().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_check_updates() {
+        use common::{MockSource, test_chapter};
+
+        let mut source = MockSource::with_pages(vec![]);
+        source.chapters = vec![
+            test_chapter("ch3", 3.0),
+            test_chapter("ch1", 1.0),
+            test_chapter("ch2", 2.0),
+        ];
+        let mut sources = Sources::new();
+        sources.add(source);
+
+        let known = [
+            ("mock", "up-to-date", 3.0),
+            ("mock", "behind", 2.0),
+            ("missing", "unknown-source", 0.0),
+        ];
+        let updates = sources.check_updates(&known, 2).await;
+
+        assert_eq!(updates.len(), 1);
+        let update = &updates[0];
+        assert_eq!(update.source_id, "mock");
+        assert_eq!(update.manga_id, "behind");
+        assert_eq!(update.last_known, 2.0);
+        assert_eq!(update.new_chapters.len(), 1);
+        assert_eq!(update.new_chapters[0].id, "ch3");
+    }
+
+    #[tokio::test]
+    async fn test_search_by_reference_reuses_params() {
+        let mut source = common::MockSource::with_pages(vec![]);
+        source.manga = vec![
+            common::test_manga("1", "One Piece"),
+            common::test_manga("2", "One Punch Man"),
+        ];
+        let mut sources = Sources::new();
+        sources.add(source);
+
+        let params = SearchParams::from("one");
+        let first = sources.search_all_flat_ref(&params).await.unwrap();
+        let second = sources.search_all_flat_ref(&params).await.unwrap();
+
+        let ids = |manga: &[Manga]| manga.iter().map(|m| m.id.clone()).collect::<Vec<_>>();
+        assert!(!first.is_empty());
+        assert_eq!(ids(&first), ids(&second));
+        // The params are still usable after both searches
+        assert_eq!(params.query, "one");
+
+        let grouped = sources.search_all_grouped_ref(&params).await;
+        assert_eq!(grouped.len(), 1);
+        assert_eq!(ids(grouped[0].1.as_ref().unwrap()), ids(&first));
+    }
+
+    #[tokio::test]
+    async fn test_disabled_sources_are_skipped_by_search() {
+        use common::{MockSource, test_manga};
+        use std::sync::atomic::Ordering;
+
+        let counting =
+            |id: &'static str| MockSource::named(id).with_manga(vec![test_manga("1", id)]);
+        let first = counting("first");
+        let second = counting("second");
+        let first_calls = first.calls.clone();
+        let second_calls = second.calls.clone();
+        let mut sources = Sources::new();
+        sources.add(first);
+        sources.add(second);
+
+        sources.set_enabled("SECOND", false).unwrap();
+        assert!(sources.is_enabled("first"));
+        assert!(!sources.is_enabled("second"));
+
+        let results = sources.search("query").flatten().await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].source_id, "first");
+        let grouped = sources
+            .search_all_grouped(SearchParams::from("query"))
+            .await;
+        assert_eq!(grouped.len(), 1);
+
+        assert_eq!(first_calls.load(Ordering::SeqCst), 2);
+        assert_eq!(second_calls.load(Ordering::SeqCst), 0);
+
+        // Disabled sources stay reachable by ID and can be enabled again
+        assert!(sources.search("query").from_source("second").await.is_ok());
+        sources.set_enabled("second", true).unwrap();
+        let results = sources.search("query").flatten().await.unwrap();
+        assert_eq!(results.len(), 2);
+
+        assert!(matches!(
+            sources.set_enabled("missing", false),
+            Err(Error::NotFound(_))
+        ));
+        assert!(!sources.is_enabled("missing"));
+    }
+
+    #[tokio::test]
+    async fn test_per_source_limit_truncates_whole_pages() {
+        use common::{MockSource, test_manga};
+
+        // Ignores the limit and returns a whole page
+        let mut sources = Sources::new();
+        sources.add(
+            MockSource::named("paged").with_manga(
+                (0..24)
+                    .map(|i| test_manga(&i.to_string(), &format!("Manga {}", i)))
+                    .collect(),
+            ),
+        );
+
+        let grouped = sources.search("manga").limit(10).group().await;
+        let (_, manga) = &grouped[0];
+        let manga = manga.as_ref().unwrap();
+        assert_eq!(manga.len(), 10);
+        assert_eq!(manga[0].id, "0");
+
+        let manga = sources
+            .search("manga")
+            .limit(10)
+            .from_source("paged")
+            .await
+            .unwrap();
+        assert_eq!(manga.len(), 10);
+
+        // Without a limit, everything the source returned is kept
+        let manga = sources.search("manga").flatten().await.unwrap();
+        assert_eq!(manga.len(), 24);
+    }
+
+    #[tokio::test]
+    async fn test_search_retrying_only_requeries_failed_sources() {
+        use common::{MockSource, test_manga};
+        use std::sync::atomic::Ordering;
+
+        let mut sources = Sources::new();
+        let mut calls = Vec::new();
+        for (id, failures, error) in [
+            (
+                "good",
+                0,
+                (|| Error::source_status("good", 503)) as fn() -> Error,
+            ),
+            ("flaky", 1, || Error::source_status("flaky", 503)),
+            ("broken", usize::MAX, || Error::parse("bad markup")),
+        ] {
+            let source = MockSource::named(id)
+                .with_manga(vec![test_manga("1", id)])
+                .failing_times(failures, error);
+            calls.push(source.calls.clone());
+            sources.add(source);
+        }
+
+        let grouped = sources
+            .search_all_grouped_retrying(SearchParams::from("query"), 3)
+            .await;
+
+        let ids: Vec<&str> = grouped.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, ["good", "flaky", "broken"]);
+        assert!(grouped[0].1.is_ok());
+        assert!(grouped[1].1.is_ok());
+        assert!(matches!(grouped[2].1, Err(Error::Parse(_))));
+
+        let counts: Vec<usize> = calls.iter().map(|c| c.load(Ordering::SeqCst)).collect();
+        // The good source is not re-queried, and a parse error is not retried
+        assert_eq!(counts, [1, 2, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_get_chapters_many_bounded_concurrency() {
+        use common::{MockSource, test_chapter};
+        use std::sync::atomic::Ordering;
+        use std::time::Duration;
+
+        let mut slow = MockSource::named("slow").with_delay(Duration::from_millis(20));
+        slow.chapters = vec![test_chapter("1", 1.0)];
+        let peak = slow.peak.clone();
+        let mut sources = Sources::new();
+        sources.add(slow);
+
+        let ids = ["a", "b", "c", "d", "e", "f", "g"];
+        let results = sources.get_chapters_many("slow", &ids, 3).await;
+
+        assert_eq!(results.len(), ids.len());
+        for id in ids {
+            let (_, result) = results.iter().find(|(manga_id, _)| manga_id == id).unwrap();
+            let chapters = result.as_ref().unwrap();
+            assert_eq!(chapters[0].manga_id, id);
+        }
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+        assert!(peak.load(Ordering::SeqCst) > 1);
+
+        // Unknown sources report an error per manga
+        let results = sources.get_chapters_many("missing", &ids[..2], 3).await;
+        assert_eq!(results.len(), 2);
+        assert!(
+            results
+                .iter()
+                .all(|(_, r)| matches!(r, Err(Error::NotFound(_))))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_latest_updates_default_sorts_by_update_time() {
+        use common::{MockSource, test_manga};
+
+        // Holds manga in update order and only returns them that way when asked to
+        let by_update = vec![
+            test_manga("newest", "Newest"),
+            test_manga("newer", "Newer"),
+            test_manga("oldest", "Oldest"),
+        ];
+        let source = MockSource::named("updates").with_search(move |params| {
+            assert!(params.query.is_empty());
+            let mut manga = by_update.clone();
+            if !matches!(params.sort_by, Some(SortOrder::UpdatedAt)) {
+                manga.reverse();
+            }
+            manga.truncate(params.limit.unwrap_or(manga.len()));
+            manga
+        });
+        let latest = source.latest_updates(2).await.unwrap();
+        let ids: Vec<&str> = latest.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["newest", "newer"]);
+
+        let mut sources = Sources::new();
+        sources.add(source);
+        sources.add(common::MockSource::with_pages(vec![]));
+
+        let all = sources.latest_updates_all(1).await;
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].0, "updates");
+        assert_eq!(all[0].1.as_ref().unwrap()[0].id, "newest");
+        assert_eq!(all[1].0, "mock");
+        assert!(all[1].1.as_ref().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_dto_round_trip() {
+        use tosho::types::{ChapterDto, MangaDto};
+
+        let mut manga = common::test_manga("dto", "Dto Manga");
+        manga.authors = vec!["Author A".to_string(), "Author B".to_string()];
+        manga.tags = vec!["Action".to_string(), "Drama".to_string()];
+
+        let dto = MangaDto::from(manga.clone());
+        let json = serde_json::to_string(&dto).unwrap();
+        let parsed: MangaDto = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, dto);
+
+        let back = Manga::from(parsed);
+        assert_eq!(back.title, manga.title);
+        assert_eq!(back.authors, manga.authors);
+        assert_eq!(back.tags, manga.tags);
+
+        let chapter = common::test_chapter("ch1", 1.5);
+        let back = Chapter::from(ChapterDto::from(chapter.clone()));
+        assert_eq!(back.number, chapter.number);
+        assert_eq!(back.title, chapter.title);
+    }
+
+    #[cfg(feature = "specta")]
+    #[test]
+    fn test_specta_exports_content_rating_as_string_union() {
+        use specta::datatype::DataType;
+        use tosho::types::specta_types;
+
+        let types = specta_types();
+        let rating = (&types)
+            .into_iter()
+            .map(|(_, ty)| ty)
+            .find(|ty| ty.name() == "ContentRating")
+            .expect("ContentRating should be registered");
+
+        let DataType::Enum(rating) = &rating.inner else {
+            panic!("ContentRating should export as an enum");
+        };
+        let variants: Vec<&str> = rating
+            .variants()
+            .iter()
+            .map(|(name, _)| name.as_ref())
+            .collect();
+        assert_eq!(variants, ["safe", "suggestive", "erotica", "pornographic"]);
+    }
+
+    #[cfg(feature = "db-sqlite")]
+    #[tokio::test]
+    async fn test_db_round_trips_manga_with_tags() {
+        use sqlx::sqlite::SqlitePoolOptions;
+        use tosho::db;
+        use tosho::types::MangaBuilder;
+
+        // Every in-memory connection is its own database, so share a single one
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        db::MIGRATOR.run(&pool).await.unwrap();
+
+        let mut manga = MangaBuilder::default()
+            .id("one-piece")
+            .title("One Piece")
+            .source_id("mock")
+            .cover_url("https://example.com/cover.jpg")
+            .authors(vec!["Oda Eiichiro".to_string()])
+            .tags(vec!["Adventure".to_string(), "Action".to_string()])
+            .build()
+            .unwrap();
+        db::upsert_manga(&pool, &manga).await.unwrap();
+
+        let stored = db::fetch_manga(&pool, "mock", "one-piece")
+            .await
+            .unwrap()
+            .expect("manga should be stored");
+        assert_eq!(stored.title, "One Piece");
+        assert_eq!(stored.cover_url, manga.cover_url);
+        assert_eq!(stored.description, None);
+        assert_eq!(stored.authors, ["Oda Eiichiro"]);
+        assert_eq!(stored.tags, ["Adventure", "Action"]);
+
+        // Upserting again replaces the tag list instead of appending to it
+        manga.tags = vec!["Comedy".to_string()];
+        db::upsert_manga(&pool, &manga).await.unwrap();
+        let stored = db::fetch_manga(&pool, "mock", "one-piece")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.tags, ["Comedy"]);
+        assert_eq!(stored.authors, ["Oda Eiichiro"]);
+
+        assert!(
+            db::fetch_manga(&pool, "other", "one-piece")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        db::upsert_chapters(
+            &pool,
+            &[
+                common::test_chapter("c2", 2.0),
+                common::test_chapter("c1", 1.0),
+            ],
+        )
+        .await
+        .unwrap();
+        let chapters = db::fetch_chapters(&pool, "mock", "test-manga")
+            .await
+            .unwrap();
+        let numbers: Vec<f64> = chapters.iter().map(|c| c.number).collect();
+        assert_eq!(numbers, [1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_stream_array_skips_malformed_elements() {
+        use tosho::net::json;
+
+        #[derive(Debug, serde::Deserialize)]
+        struct Entry {
+            id: String,
+        }
+
+        let body = br#"{"result":"ok","data":[{"id":"a"},{"id":7},{"id":"c"}],"total":3}"#;
+        let mut entries = json::stream_array::<Entry>(body, "/data").unwrap();
+
+        assert_eq!(entries.next().unwrap().unwrap().id, "a");
+        assert!(matches!(entries.next(), Some(Err(Error::Json(_)))));
+        assert_eq!(entries.next().unwrap().unwrap().id, "c");
+        assert!(entries.next().is_none());
+
+        assert!(json::stream_array::<Entry>(body, "/missing").is_err());
+        assert!(json::stream_array::<Entry>(body, "/result").is_err());
+        assert_eq!(
+            json::stream_array::<u32>(b"[1, 2, 3]", "").unwrap().count(),
+            3
+        );
     }
 }